@@ -8,6 +8,7 @@ use brunch::{
 };
 use oxford_join::{
 	Conjunction,
+	JoinFmt,
 	OxfordJoin,
 };
 use std::collections::{
@@ -34,12 +35,21 @@ fn main() {
 	let map = FIVE.into_iter().enumerate().collect::<BTreeMap<usize, &str>>();
 	let set = BTreeSet::from(FIVE);
 	let set2 = HashSet::from(FIVE);
+	let mut buf = String::new();
+	let thousand: Vec<String> = (1000..2000_u32).map(|n| n.to_string()).collect();
+	let thousand_str: Vec<&str> = thousand.iter().map(String::as_str).collect();
+	let und = Conjunction::Other("und");
+	let und_resolved = und.resolve();
+	let big: Vec<String> = (b'A'..=b'J')
+		.map(|b| char::from(b).to_string().repeat(1024))
+		.collect();
 
 	benches!(
 		inline:
 
 		Bench::new("<[T; 1]>::oxford_and()").run(|| ONE.oxford_and()),
 		Bench::new("<[T; 2]>::oxford_and()").run(|| TWO.oxford_and()),
+		Bench::new("<[T; 2]>::oxford_join_small(And)").run(|| TWO.oxford_join_small(Conjunction::And)),
 		Bench::new("<[T; 3]>::oxford_and()").run(|| THREE.oxford_and()),
 		Bench::new("<[T; 5]>::oxford_and()").run(|| FIVE.oxford_and()),
 		Bench::new("<[T; 32]>::oxford_and()").run(|| THIRTYTWO.oxford_and()),
@@ -61,6 +71,65 @@ fn main() {
 
 		Bench::spacer(),
 
+		Bench::new("Conjunction::And.oxford_join([T; 5]) x1000")
+			.run(|| {
+				let mut len = 0;
+				for _ in 0..1000 { len += Conjunction::And.oxford_join(FIVE).len(); }
+				len
+			}),
+		Bench::new("Conjunction::And.oxford_join_into([T; 5]) x1000")
+			.run(|| {
+				let mut len = 0;
+				for _ in 0..1000 {
+					Conjunction::And.oxford_join_into(FIVE, &mut buf);
+					len += buf.len();
+				}
+				len
+			}),
+
+		Bench::spacer(),
+
+		// Precise (two-pass) vs. estimated (one-pass) capacity.
+		Bench::new("<[String; 1000]>::oxford_and()").run(|| thousand.oxford_and()),
+		Bench::new("<[&str; 1000]>::oxford_and()").run(|| thousand_str.oxford_and()),
+		Bench::new("Conjunction::And.oxford_join(<1000>.iter())")
+			.run(|| Conjunction::And.oxford_join(thousand.iter())),
+		Bench::new("Conjunction::And.oxford_join(<&str; 1000>.iter())")
+			.run(|| Conjunction::And.oxford_join(thousand_str.iter())),
+		Bench::new("Conjunction::And.oxford_join(<1000>.iter().filter(…))")
+			.run(|| Conjunction::And.oxford_join(thousand.iter().filter(|_| true))),
+
+		Bench::spacer(),
+
+		// Batch joins with a custom conjunction.
+		Bench::new("<[T; 3]>::oxford_join(Other(\"und\")) x1000")
+			.run(|| {
+				let mut len = 0;
+				for _ in 0..1000 { len += THREE.oxford_join(und).len(); }
+				len
+			}),
+		Bench::new("Other(\"und\").resolve().oxford_join(<[T; 3]>) x1000")
+			.run(|| {
+				let mut len = 0;
+				for _ in 0..1000 { len += und_resolved.oxford_join(&THREE).len(); }
+				len
+			}),
+
+		Bench::spacer(),
+
+		Bench::new("<[String]>::oxford_and() (10x1KiB)").run(|| big.oxford_and()),
+		Bench::new("Conjunction::And.oxford_join(&[String]) (10x1KiB)")
+			.run(|| Conjunction::And.oxford_join(&big)),
+
+		Bench::spacer(),
+
+		Bench::new("JoinFmt::new(<100>.iter(), \", \").to_string()")
+			.run(|| JoinFmt::new(thousand[..100].iter(), ", ").to_string()),
+		Bench::new("JoinFmt::new(<100>.iter(), \", \").to_string_sized()")
+			.run(|| JoinFmt::new(thousand[..100].iter(), ", ").to_string_sized()),
+
+		Bench::spacer(),
+
 		Bench::new(r#"<[T; 32]>::join(", ")"#).run(|| THIRTYTWO.join(", ")),
 	);
 }
@@ -0,0 +1,86 @@
+/*!
+# Benchmark: Oxford Join (Sweep)
+
+This sweeps a range of set sizes and conjunction types to catch regressions
+in the length precomputation, etc.
+
+To keep the timings comparable across sizes, each benchmark joins enough sets
+to process (about) 1,000 items total, i.e. the reported times are per 1,000
+items rather than per join.
+*/
+
+use brunch::{
+	Bench,
+	Benches,
+};
+use oxford_join::{
+	Conjunction,
+	OxfordJoin,
+};
+
+
+
+/// # Set Sizes.
+const SIZES: [usize; 6] = [1, 2, 3, 10, 100, 1000];
+
+/// # Conjunctions.
+const GLUE: [Conjunction<'static>; 3] = [
+	Conjunction::And,
+	Conjunction::Ampersand,
+	Conjunction::Other("und"),
+];
+
+/// # Items per Benchmark.
+const ITEMS: usize = 1000;
+
+
+
+fn main() {
+	let all: Vec<String> = (0..1000_u32).map(|n| format!("Item #{n}")).collect();
+	let mut benches = Benches::default();
+
+	for (idx, glue) in GLUE.into_iter().enumerate() {
+		if idx != 0 { benches.push(Bench::spacer()); }
+		for size in SIZES {
+			let set = &all[..size];
+			let reps = ITEMS / size;
+			check(set, glue);
+
+			benches.push(
+				Bench::new(format!("<[String]>::oxford_join({glue:?}) ({size} x {reps})"))
+					.run(|| {
+						let mut len = 0;
+						for _ in 0..reps { len += set.oxford_join(glue).len(); }
+						len
+					})
+			);
+			benches.push(
+				Bench::new(format!("Conjunction::{glue:?}.oxford_join(<{size}>.iter()) ({size} x {reps})"))
+					.run(|| {
+						let mut len = 0;
+						for _ in 0..reps { len += glue.oxford_join(set.iter()).len(); }
+						len
+					})
+			);
+		}
+	}
+
+	benches.finish();
+}
+
+/// # Sanity Check.
+///
+/// Make sure the output lengths line up with the public length helpers
+/// before we bother timing anything.
+fn check(set: &[String], glue: Conjunction) {
+	let items: usize = set.iter().map(String::len).sum();
+	let expected = match set.len() {
+		0 | 1 => items,
+		2 => items + glue.pair_glue_len(),
+		n => items + glue.list_glue_len() + (n - 2) * 2,
+	};
+
+	let out = set.oxford_join(glue);
+	assert_eq!(out.len(), expected, "Length mismatch: {glue:?} x {}", set.len());
+	assert_eq!(glue.oxford_join(set).len(), expected, "Length mismatch (generic): {glue:?} x {}", set.len());
+}
@@ -0,0 +1,97 @@
+/*!
+# Oxford Join: Fuzz Join.
+
+The first byte picks the conjunction; the rest is split on NULs, the first
+chunk serving as the custom conjunction/separator, the others as the set.
+
+Every implementation should agree with the generic iterator join, and should
+allocate exactly once, exactly. (Large slices estimate instead, so are only
+held to reasonable bounds.)
+*/
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use oxford_join::{
+	Conjunction,
+	JoinStyle,
+	OxfordJoin,
+};
+use std::{
+	borrow::Cow,
+	collections::{
+		BTreeSet,
+		VecDeque,
+	},
+};
+
+fuzz_target!(|data: &[u8]| {
+	let Some((&op, data)) = data.split_first() else { return; };
+	let Ok(data) = std::str::from_utf8(data) else { return; };
+	let mut parts = data.split('\0');
+	let custom = parts.next().unwrap_or_default();
+	let set: Vec<&str> = parts.collect();
+
+	let glue = match op % 10 {
+		0 => Conjunction::Ampersand,
+		1 => Conjunction::And,
+		2 => Conjunction::AndOr,
+		3 => Conjunction::Comma,
+		4 => Conjunction::Nor,
+		5 => Conjunction::Or,
+		6 => Conjunction::Plus,
+		7 => Conjunction::Other(custom),
+		8 => Conjunction::OtherRaw { pair: custom, list: custom },
+		_ => Conjunction::Dynamic(|s| if s.len() % 2 == 0 { "y" } else { "e" }),
+	};
+
+	// The reference join.
+	let expected = glue.oxford_join(set.iter());
+
+	// Slices and vectors.
+	check(set.as_slice(), glue, &expected, false);
+	check(&set, glue, &expected, false);
+	check(&set.iter().map(|s| (*s).to_owned()).collect::<Vec<String>>(), glue, &expected, false);
+
+	// Deques.
+	check(&set.iter().copied().collect::<VecDeque<&str>>(), glue, &expected, true);
+
+	// Arrays.
+	macro_rules! arrays {
+		($($num:literal),+) => ($(
+			if let Ok(arr) = <[&str; $num]>::try_from(set.as_slice()) {
+				check(&arr, glue, &expected, true);
+			}
+		)+);
+	}
+	arrays!(0, 1, 2, 3, 4, 5, 6, 7, 8);
+
+	// Sets sort and dedup, so need their own reference.
+	let tree: BTreeSet<&str> = set.iter().copied().collect();
+	check(&tree, glue, &glue.oxford_join(tree.iter()), true);
+
+	// Styles.
+	let style = JoinStyle::new(glue).with_separator(custom);
+	let out = set.oxford_join_styled(style);
+	if let Cow::Owned(out) = &out { assert!(out.len() <= out.capacity(), "Styled capacity."); }
+	if custom == ", " { assert_eq!(out, expected, "Styled mismatch."); }
+});
+
+/// # Check Join.
+///
+/// Make sure the join matches the reference, and that its capacity is exact,
+/// or for slices — which estimate when large — at least sufficient and not
+/// grossly overblown.
+fn check<S: OxfordJoin + ?Sized>(set: &S, glue: Conjunction, expected: &str, exact: bool) {
+	let out = set.oxford_join(glue);
+	assert_eq!(out, expected, "Join mismatch.");
+	match out {
+		Cow::Borrowed(_) => assert!(set.oxford_count() < 2, "Large joins should be owned."),
+		Cow::Owned(out) =>
+			if exact { assert_eq!(out.len(), out.capacity(), "Inexact capacity."); }
+			else {
+				assert!(out.len() <= out.capacity(), "Insufficient capacity.");
+				assert!(out.capacity() <= out.len() * 2, "Gross over-allocation.");
+			},
+	}
+}
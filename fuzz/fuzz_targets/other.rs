@@ -0,0 +1,97 @@
+/*!
+# Oxford Join: Fuzz Other.
+
+The input is split on NULs, the first chunk serving as the custom
+`Conjunction::Other` word, the rest as the set. Anything goes: huge words,
+commas, control characters, etc.
+
+Joins should never panic, should match a naive reconstruction of the
+expected structure, and should allocate exactly once, exactly. (Large slices
+estimate instead, so are only held to reasonable bounds.)
+*/
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use oxford_join::{
+	Conjunction,
+	OxfordJoin,
+};
+use std::{
+	borrow::Cow,
+	collections::VecDeque,
+};
+
+fuzz_target!(|data: &[u8]| {
+	let Ok(data) = std::str::from_utf8(data) else { return; };
+	let mut parts = data.split('\0');
+	let word = parts.next().unwrap_or_default();
+	let set: Vec<&str> = parts.collect();
+	let glue = Conjunction::Other(word);
+
+	// Build the expected output the long way.
+	let expected = match set.as_slice() {
+		[] => String::new(),
+		[a] => (*a).to_owned(),
+		[a, b] => format!("{a} {word} {b}"),
+		[rest @ .., last] => format!("{}, {word} {last}", rest.join(", ")),
+	};
+
+	// The lengths should line up.
+	let glue_len = match set.len() {
+		0 | 1 => 0,
+		2 => glue.pair_glue_len(),
+		n => glue.list_glue_len() + (n - 2) * 2,
+	};
+	assert_eq!(
+		expected.len(),
+		set.iter().map(|s| s.len()).sum::<usize>() + glue_len,
+		"Glue length mismatch.",
+	);
+
+	// Slices, vectors, and deques.
+	check(set.as_slice(), glue, &expected, false);
+	check(&set, glue, &expected, false);
+	check(&set.iter().copied().collect::<VecDeque<&str>>(), glue, &expected, true);
+
+	// Arrays.
+	macro_rules! arrays {
+		($($num:literal),+) => ($(
+			if let Ok(arr) = <[&str; $num]>::try_from(set.as_slice()) {
+				check(&arr, glue, &expected, true);
+			}
+		)+);
+	}
+	arrays!(2, 3, 4, 5);
+
+	// The generic iterator join.
+	assert_eq!(glue.oxford_join(set.iter()), expected, "Iter mismatch.");
+
+	// The no-alloc slice join, with room to spare and without.
+	let mut buf = vec![0_u8; expected.len()];
+	let len = oxford_join::oxford_join_slice(&set, glue, &mut buf).expect("Slice join failed.");
+	assert_eq!(&buf[..len], expected.as_bytes(), "Slice mismatch.");
+	if let Some(short) = expected.len().checked_sub(1) {
+		let err = oxford_join::oxford_join_slice(&set, glue, &mut buf[..short]).unwrap_err();
+		assert_eq!(err.needed(), expected.len(), "Slice needed mismatch.");
+	}
+});
+
+/// # Check Join.
+///
+/// Make sure the join matches the expected output, and that its capacity is
+/// exact, or for slices — which estimate when large — at least sufficient and
+/// not grossly overblown.
+fn check<S: OxfordJoin + ?Sized>(set: &S, glue: Conjunction, expected: &str, exact: bool) {
+	let out = set.oxford_join(glue);
+	assert_eq!(out, expected, "Join mismatch.");
+	match out {
+		Cow::Borrowed(_) => assert!(set.oxford_count() < 2, "Large joins should be owned."),
+		Cow::Owned(out) =>
+			if exact { assert_eq!(out.len(), out.capacity(), "Inexact capacity."); }
+			else {
+				assert!(out.len() <= out.capacity(), "Insufficient capacity.");
+				assert!(out.capacity() <= out.len() * 2, "Gross over-allocation.");
+			},
+	}
+}
@@ -0,0 +1,120 @@
+/*!
+# Oxford Join: Borrow Joins.
+*/
+
+use alloc::borrow::Cow;
+use core::borrow::Borrow;
+use crate::{
+	Conjunction,
+	JoinStyle,
+	OxfordJoin,
+};
+
+
+
+/// # Oxford Join Borrowed.
+///
+/// [`OxfordJoin`] is implemented for slices of [`AsRef<str>`] items, but some
+/// types only implement [`Borrow<str>`](core::borrow::Borrow) (the trait used
+/// for map and set lookups). This extension trait fills the gap, joining
+/// slices of the latter instead.
+///
+/// The output is exactly the same as [`OxfordJoin::oxford_join`]'s.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordJoinBorrowed};
+/// use std::borrow::Borrow;
+///
+/// struct Fruit(String);
+/// impl Borrow<str> for Fruit {
+///     fn borrow(&self) -> &str { &self.0 }
+/// }
+///
+/// let set = [
+///     Fruit(String::from("Apples")),
+///     Fruit(String::from("Oranges")),
+///     Fruit(String::from("Bananas")),
+/// ];
+/// assert_eq!(
+///     set.oxford_join_borrowed(Conjunction::And),
+///     "Apples, Oranges, and Bananas",
+/// );
+/// ```
+pub trait OxfordJoinBorrowed {
+	/// # Oxford Join (Borrowed).
+	///
+	/// Join the items with Oxford Commas as necessary.
+	fn oxford_join_borrowed(&self, glue: Conjunction) -> Cow<'_, str>;
+}
+
+impl<T: Borrow<str>> OxfordJoinBorrowed for [T] {
+	fn oxford_join_borrowed(&self, glue: Conjunction) -> Cow<'_, str> {
+		match self {
+			[] => Cow::Borrowed(""),
+			[one] => Cow::Borrowed(one.borrow()),
+			// The output is always owned here; the wrapper just needs to
+			// outlive the join.
+			_ => Cow::Owned(Borrowed(self).oxford_join(glue).into_owned()),
+		}
+	}
+}
+
+
+
+/// # Borrow Wrapper.
+///
+/// This wraps a slice of [`Borrow<str>`] items so it can be fed to the usual
+/// join machinery.
+struct Borrowed<'a, T>(&'a [T]);
+
+impl<T: Borrow<str>> OxfordJoin for Borrowed<'_, T> {
+	#[inline]
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+		crate::join_styled(self, JoinStyle::new(glue))
+	}
+
+	#[inline]
+	fn oxford_count(&self) -> usize { self.0.len() }
+
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+		for v in self.0 { cb(v.borrow()); }
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::{
+		cross_check,
+		FRUIT,
+	};
+	use alloc::{
+		borrow::ToOwned,
+		string::String,
+		vec::Vec,
+	};
+
+	/// # Borrow-Only Type.
+	struct Name(String);
+
+	impl Borrow<str> for Name {
+		fn borrow(&self) -> &str { &self.0 }
+	}
+
+	#[test]
+	fn t_oxford_join_borrowed() {
+		let names: Vec<Name> = FRUIT.iter().map(|&s| Name(s.to_owned())).collect();
+		cross_check(|set, c| names[..set.len()].oxford_join_borrowed(c));
+
+		// Small sets should be borrowed.
+		assert!(matches!(names[..1].oxford_join_borrowed(Conjunction::And), Cow::Borrowed("Apples")));
+
+		// Dynamic glue should be resolved.
+		let glue = Conjunction::Dynamic(|s| if s.starts_with('D') { "&" } else { "+" });
+		assert_eq!(names[..4].oxford_join_borrowed(glue), "Apples, Bananas, Carrots, & Dates");
+	}
+}
@@ -0,0 +1,117 @@
+/*!
+# Oxford Join: Projected Joins.
+*/
+
+use alloc::borrow::Cow;
+use crate::{
+	Conjunction,
+	JoinStyle,
+	OxfordJoin,
+};
+
+
+
+/// # Oxford Join By.
+///
+/// This extension trait lets slices of _anything_ be Oxford-joined, so long
+/// as each element can be projected to a string slice, e.g. a struct field
+/// or tuple member.
+///
+/// The output length is computed from the projected values, so the result is
+/// allocated exactly once.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordJoinBy};
+///
+/// let set = [
+///     (String::from("Apples"), 3_u32),
+///     (String::from("Oranges"), 5),
+///     (String::from("Bananas"), 1),
+/// ];
+/// assert_eq!(
+///     set.oxford_join_by(Conjunction::And, |(name, _)| name),
+///     "Apples, Oranges, and Bananas",
+/// );
+/// ```
+pub trait OxfordJoinBy<T> {
+	/// # Oxford Join (Projected).
+	///
+	/// Join the string slices returned by `key` for each element with Oxford
+	/// Commas as necessary.
+	fn oxford_join_by<F>(&self, glue: Conjunction, key: F) -> Cow<'_, str>
+	where F: Fn(&T) -> &str;
+}
+
+impl<T> OxfordJoinBy<T> for [T] {
+	fn oxford_join_by<F>(&self, glue: Conjunction, key: F) -> Cow<'_, str>
+	where F: Fn(&T) -> &str {
+		match self {
+			[] => Cow::Borrowed(""),
+			[one] => Cow::Borrowed(key(one)),
+			// The output is always owned here; the wrapper just needs to
+			// outlive the join.
+			_ => Cow::Owned(By(self, key).oxford_join(glue).into_owned()),
+		}
+	}
+}
+
+
+
+/// # Projection Wrapper.
+///
+/// This wraps a slice and projection so it can be fed to the usual join
+/// machinery.
+struct By<'a, T, F>(&'a [T], F);
+
+impl<T, F> OxfordJoin for By<'_, T, F>
+where F: Fn(&T) -> &str {
+	#[inline]
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+		crate::join_styled(self, JoinStyle::new(glue))
+	}
+
+	#[inline]
+	fn oxford_count(&self) -> usize { self.0.len() }
+
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+		for v in self.0 { cb((self.1)(v)); }
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::{
+		cross_check,
+		FRUIT,
+	};
+
+	/// # Test Struct.
+	struct Fruit {
+		/// # Name.
+		name: &'static str,
+
+		/// # Count.
+		count: u32,
+	}
+
+	#[test]
+	fn t_oxford_join_by() {
+		let fruit = FRUIT.map(|name| Fruit { name, count: 1 });
+		let pairs = FRUIT.map(|name| (name, 1_u32));
+		cross_check(|set, c| fruit[..set.len()].oxford_join_by(c, |f| f.name));
+		cross_check(|set, c| pairs[..set.len()].oxford_join_by(c, |p| p.0));
+
+		assert!(fruit.iter().all(|f| f.count == 1));
+
+		// Single items should be borrowed.
+		assert!(matches!(
+			fruit[..1].oxford_join_by(Conjunction::And, |f| f.name),
+			Cow::Borrowed("Apples"),
+		));
+	}
+}
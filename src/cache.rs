@@ -0,0 +1,339 @@
+/*!
+# Oxford Join: Caching.
+*/
+
+use crate::{
+	Conjunction,
+	OxfordJoin,
+};
+use std::{
+	boxed::Box,
+	collections::HashMap,
+	hash::{
+		BuildHasher,
+		Hash,
+		Hasher,
+		RandomState,
+	},
+	sync::Arc,
+	vec::Vec,
+};
+
+
+
+/// # Oxford Cache.
+///
+/// This is a memoizing wrapper for programs that join the same (small) sets
+/// over and over again, like a fixed list of feature flags rendered every
+/// frame.
+///
+/// Results are keyed by the set contents and conjunction, and returned as
+/// cheaply-cloneable `Arc<str>`s. Conjunctions that render identically — see
+/// [`Conjunction::semantic_eq`] — share entries.
+///
+/// ## Capacity and Invalidation.
+///
+/// Because the keys are the contents themselves, entries never go stale, and
+/// so never need to be invalidated.
+///
+/// To keep memory usage in check, though, the cache holds at most
+/// [`OxfordCache::capacity`] entries. When a new join would exceed that, the
+/// cache is simply cleared first, and starts filling up again. (For the
+/// intended use case — a small working set of repeated joins — this keeps
+/// things fast and bounded without the overhead of LRU bookkeeping.)
+///
+/// [`OxfordCache::clear`] can also be called manually at any time.
+///
+/// A capacity of zero disables caching entirely.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordCache};
+/// use std::sync::Arc;
+///
+/// let mut cache = OxfordCache::default();
+/// let flags = ["avx2", "bmi2", "sse4.2"];
+///
+/// let a = cache.get_or_join(&flags, Conjunction::And);
+/// assert_eq!(&*a, "avx2, bmi2, and sse4.2");
+///
+/// // The second time around, the result is shared.
+/// let b = cache.get_or_join(&flags, Conjunction::And);
+/// assert!(Arc::ptr_eq(&a, &b));
+/// assert_eq!(cache.len(), 1);
+/// ```
+pub struct OxfordCache {
+	/// # Entries (by Hash).
+	map: HashMap<u64, Vec<Entry>>,
+
+	/// # Hasher.
+	hasher: RandomState,
+
+	/// # Entry Count.
+	len: usize,
+
+	/// # Maximum Entries.
+	capacity: usize,
+}
+
+impl Default for OxfordCache {
+	#[inline]
+	fn default() -> Self { Self::new(Self::DEFAULT_CAPACITY) }
+}
+
+impl core::fmt::Debug for OxfordCache {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("OxfordCache")
+			.field("len", &self.len)
+			.field("capacity", &self.capacity)
+			.finish_non_exhaustive()
+	}
+}
+
+impl OxfordCache {
+	/// # Default Capacity.
+	pub const DEFAULT_CAPACITY: usize = 256;
+
+	#[must_use]
+	/// # New.
+	///
+	/// Create an empty cache holding at most `capacity` entries.
+	pub fn new(capacity: usize) -> Self {
+		Self {
+			map: HashMap::new(),
+			hasher: RandomState::new(),
+			len: 0,
+			capacity,
+		}
+	}
+
+	#[must_use]
+	/// # Capacity.
+	///
+	/// Return the maximum number of entries the cache will hold before
+	/// clearing itself.
+	pub const fn capacity(&self) -> usize { self.capacity }
+
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the number of cached entries.
+	pub const fn len(&self) -> usize { self.len }
+
+	#[must_use]
+	/// # Is Empty?
+	pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+	/// # Clear.
+	///
+	/// Remove all cached entries.
+	pub fn clear(&mut self) {
+		self.map.clear();
+		self.len = 0;
+	}
+
+	/// # Get or Join.
+	///
+	/// Return the cached join for the set and conjunction, or join it,
+	/// cache it, and return that.
+	pub fn get_or_join<T: AsRef<str>>(&mut self, set: &[T], glue: Conjunction)
+	-> Arc<str> {
+		if self.capacity == 0 { return Arc::from(&*set.oxford_join(glue)); }
+
+		// Hash the contents.
+		let mut h = self.hasher.build_hasher();
+		set.len().hash(&mut h);
+		for s in set { s.as_ref().hash(&mut h); }
+		match Glue::parts(&glue) {
+			Ok([pair, list]) => {
+				for p in pair { h.write(p.as_bytes()); }
+				h.write_u8(0xff);
+				for p in list { h.write(p.as_bytes()); }
+				h.write_u8(0xff);
+			},
+			Err(f) => { (f as usize).hash(&mut h); },
+		}
+		let hash = h.finish();
+
+		// Return it if we've got it.
+		if let Some(out) = self.map.get(&hash).and_then(|bucket|
+			bucket.iter().find(|e| e.matches(set, glue))
+		) {
+			return Arc::clone(&out.out);
+		}
+
+		// Make room if needed.
+		if self.capacity <= self.len { self.clear(); }
+
+		// Join and save it.
+		let out: Arc<str> = Arc::from(&*set.oxford_join(glue));
+		self.map.entry(hash).or_default().push(Entry {
+			items: set.iter().map(|s| Box::from(s.as_ref())).collect(),
+			glue: Glue::new(glue),
+			out: Arc::clone(&out),
+		});
+		self.len += 1;
+		out
+	}
+}
+
+
+
+/// # Dynamic Selector.
+type Selector = fn(&str) -> &'static str;
+
+/// # Cache Entry.
+struct Entry {
+	/// # Items.
+	items: Box<[Box<str>]>,
+
+	/// # Glue.
+	glue: Glue,
+
+	/// # Output.
+	out: Arc<str>,
+}
+
+impl Entry {
+	/// # Matches?
+	fn matches<T: AsRef<str>>(&self, set: &[T], glue: Conjunction) -> bool {
+		self.items.len() == set.len() &&
+		self.items.iter().zip(set).all(|(a, b)| &**a == b.as_ref()) &&
+		self.glue.matches(glue)
+	}
+}
+
+
+
+/// # Cached Glue.
+///
+/// Conjunctions are stored by their rendered parts, so any that render the
+/// same way share entries.
+enum Glue {
+	/// # Fixed.
+	Fixed {
+		/// # Two-Item Glue.
+		pair: Box<str>,
+
+		/// # Three+ Final Glue.
+		list: Box<str>,
+	},
+
+	/// # Dynamic.
+	Dynamic(Selector),
+}
+
+impl Glue {
+	/// # New.
+	fn new(glue: Conjunction) -> Self {
+		match Self::parts(&glue) {
+			Ok([pair, list]) => Self::Fixed {
+				pair: pair.concat().into_boxed_str(),
+				list: list.concat().into_boxed_str(),
+			},
+			Err(f) => Self::Dynamic(f),
+		}
+	}
+
+	/// # Rendered Parts.
+	///
+	/// Return the pair and list parts, or the selector function for dynamic
+	/// conjunctions.
+	fn parts<'a>(glue: &'a Conjunction) -> Result<[[&'a str; 3]; 2], Selector> {
+		if let Conjunction::Dynamic(f) = *glue { Err(f) }
+		else { Ok([glue.pair_parts(), glue.list_parts(", ")]) }
+	}
+
+	/// # Matches?
+	fn matches(&self, glue: Conjunction) -> bool {
+		/// # Same Parts?
+		fn same(a: &str, b: [&str; 3]) -> bool {
+			a.bytes().eq(b.iter().flat_map(|s| s.bytes()))
+		}
+
+		match (self, Self::parts(&glue)) {
+			(Self::Fixed { pair, list }, Ok([p, l])) => same(pair, p) && same(list, l),
+			(Self::Dynamic(a), Err(b)) => core::ptr::eq(*a as *const (), b as *const ()),
+			_ => false,
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::{
+		cross_check,
+		FRUIT,
+	};
+	use std::string::String;
+
+	#[test]
+	fn t_oxford_cache() {
+		let mut cache = OxfordCache::default();
+		assert!(cache.is_empty());
+		assert_eq!(cache.capacity(), OxfordCache::DEFAULT_CAPACITY);
+
+		let mut joins = 0;
+		cross_check(|set, c| {
+			let a = cache.get_or_join(set, c);
+			let b = cache.get_or_join(set, c);
+			assert!(Arc::ptr_eq(&a, &b), "Cache miss.");
+			joins += 1;
+			a
+		});
+		assert_eq!(cache.len(), joins);
+
+		// Equivalent conjunctions and item types should share.
+		let a = cache.get_or_join(&FRUIT, Conjunction::Other("and"));
+		let b = cache.get_or_join(&FRUIT.map(String::from), Conjunction::And);
+		assert!(Arc::ptr_eq(&a, &b));
+
+		// But different ones shouldn't.
+		let c = cache.get_or_join(&FRUIT, Conjunction::OtherRaw { pair: " and ", list: " and " });
+		assert_eq!(&*c, "Apples, Bananas, Carrots, Dates and Eggplant");
+
+		// Item boundaries matter.
+		let a = cache.get_or_join(&["ab", "c", "d"], Conjunction::And);
+		let b = cache.get_or_join(&["a", "bc", "d"], Conjunction::And);
+		assert_eq!(&*a, "ab, c, and d");
+		assert_eq!(&*b, "a, bc, and d");
+
+		// Dynamic conjunctions work too.
+		let glue = Conjunction::Dynamic(|s| if s.starts_with('E') { "e" } else { "y" });
+		let a = cache.get_or_join(&FRUIT, glue);
+		assert_eq!(&*a, "Apples, Bananas, Carrots, Dates, e Eggplant");
+		assert!(Arc::ptr_eq(&a, &cache.get_or_join(&FRUIT, glue)));
+
+		cache.clear();
+		assert!(cache.is_empty());
+	}
+
+	#[test]
+	fn t_oxford_cache_capacity() {
+		const SET: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+
+		// Zero-capacity caches don't cache.
+		let mut cache = OxfordCache::new(0);
+		let a = cache.get_or_join(&SET, Conjunction::And);
+		let b = cache.get_or_join(&SET, Conjunction::And);
+		assert_eq!(a, b);
+		assert!(! Arc::ptr_eq(&a, &b));
+		assert!(cache.is_empty());
+
+		// Full caches start over.
+		let mut cache = OxfordCache::new(2);
+		let a = cache.get_or_join(&SET, Conjunction::And);
+		cache.get_or_join(&SET, Conjunction::Or);
+		assert_eq!(cache.len(), 2);
+		assert!(Arc::ptr_eq(&a, &cache.get_or_join(&SET, Conjunction::And)));
+
+		cache.get_or_join(&SET, Conjunction::Nor);
+		assert_eq!(cache.len(), 1);
+		assert!(! Arc::ptr_eq(&a, &cache.get_or_join(&SET, Conjunction::And)));
+		assert_eq!(cache.len(), 2);
+	}
+}
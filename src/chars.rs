@@ -0,0 +1,100 @@
+/*!
+# Oxford Join: Character Joins.
+*/
+
+use alloc::borrow::Cow;
+use crate::{
+	Conjunction,
+	JoinStyle,
+	OxfordJoin,
+};
+
+
+
+/// # Oxford Join Chars.
+///
+/// This extension trait lets a string be Oxford-joined _character by
+/// character_, handy for spelling things out.
+///
+/// Each item is borrowed straight from the source — multi-byte characters
+/// included — so the only allocation is the output itself.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordJoinChars};
+///
+/// assert_eq!("abc".oxford_chars(Conjunction::And), "a, b, and c");
+/// assert_eq!("ñü".oxford_chars(Conjunction::Or), "ñ or ü");
+///
+/// // Small sets are small.
+/// assert_eq!("a".oxford_chars(Conjunction::And), "a");
+/// assert_eq!("".oxford_chars(Conjunction::And), "");
+/// ```
+pub trait OxfordJoinChars {
+	/// # Oxford Join (Chars).
+	///
+	/// Join each character with Oxford Commas as necessary.
+	fn oxford_chars(&self, glue: Conjunction) -> Cow<'_, str>;
+}
+
+impl OxfordJoinChars for str {
+	fn oxford_chars(&self, glue: Conjunction) -> Cow<'_, str> {
+		// Zero or one chars can be returned as-is.
+		let mut iter = self.chars();
+		if iter.next().is_none() || iter.next().is_none() { Cow::Borrowed(self) }
+		// Otherwise the output is always owned; the wrapper just needs to
+		// outlive the join.
+		else { Cow::Owned(Chars(self).oxford_join(glue).into_owned()) }
+	}
+}
+
+
+
+/// # Character Wrapper.
+///
+/// This wraps a string, presenting each of its characters as a separate item.
+struct Chars<'a>(&'a str);
+
+impl OxfordJoin for Chars<'_> {
+	#[inline]
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+		crate::join_styled(self, JoinStyle::new(glue))
+	}
+
+	#[inline]
+	fn oxford_count(&self) -> usize { self.0.chars().count() }
+
+	fn oxford_walk<'b>(&'b self, cb: &mut dyn FnMut(&'b str)) {
+		for (idx, c) in self.0.char_indices() {
+			cb(&self.0[idx..idx + c.len_utf8()]);
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_oxford_chars() {
+		for (raw, expected) in [
+			("", ""),
+			("a", "a"),
+			("ab", "a and b"),
+			("abc", "a, b, and c"),
+			("a😀c", "a, 😀, and c"),
+			("😀", "😀"),
+			("日本語", "日, 本, and 語"),
+		] {
+			let out = raw.oxford_chars(Conjunction::And);
+			assert_eq!(out, expected);
+			assert_eq!(out, Conjunction::And.oxford_join_chars(raw.chars()));
+		}
+
+		// Single characters should be borrowed.
+		assert!(matches!("😀".oxford_chars(Conjunction::And), Cow::Borrowed("😀")));
+	}
+}
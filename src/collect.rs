@@ -0,0 +1,155 @@
+/*!
+# Oxford Join: Iterator Collection.
+*/
+
+use alloc::string::String;
+use crate::Conjunction;
+
+
+
+/// # Collect Oxford.
+///
+/// This extension trait lets any iterator of `AsRef<str>` items be
+/// Oxford-joined at the _end_ of a chain, just like `collect`. It is
+/// implemented automatically; all you have to do is bring it into scope.
+///
+/// The heavy lifting is handled by [`Conjunction::oxford_join`].
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{CollectOxford, Conjunction};
+///
+/// let out = ["apples", "", "oranges", "bananas"]
+///     .into_iter()
+///     .filter(|s| ! s.is_empty())
+///     .map(str::to_uppercase)
+///     .collect_oxford(Conjunction::And);
+/// assert_eq!(out, "APPLES, ORANGES, and BANANAS");
+/// ```
+pub trait CollectOxford: Iterator + Sized
+where Self::Item: AsRef<str> {
+	#[inline]
+	/// # Collect Oxford.
+	///
+	/// Consume the iterator, joining its items with Oxford Commas as
+	/// necessary.
+	fn collect_oxford(self, glue: Conjunction) -> String { glue.oxford_join(self) }
+
+	#[inline]
+	/// # Collect Oxford (and).
+	///
+	/// This is equivalent to calling `collect_oxford(Conjunction::And)`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::CollectOxford;
+	///
+	/// let out = (1..=3).map(|n| n.to_string()).collect_oxford_and();
+	/// assert_eq!(out, "1, 2, and 3");
+	/// ```
+	fn collect_oxford_and(self) -> String { self.collect_oxford(Conjunction::And) }
+
+	#[inline]
+	/// # Collect Oxford (and/or).
+	///
+	/// This is equivalent to calling `collect_oxford(Conjunction::AndOr)`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::CollectOxford;
+	///
+	/// let out = (1..=3).map(|n| n.to_string()).collect_oxford_and_or();
+	/// assert_eq!(out, "1, 2, and/or 3");
+	/// ```
+	fn collect_oxford_and_or(self) -> String { self.collect_oxford(Conjunction::AndOr) }
+
+	#[inline]
+	/// # Collect Oxford (nor).
+	///
+	/// This is equivalent to calling `collect_oxford(Conjunction::Nor)`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::CollectOxford;
+	///
+	/// let out = (1..=3).map(|n| n.to_string()).collect_oxford_nor();
+	/// assert_eq!(out, "1, 2, nor 3");
+	/// ```
+	fn collect_oxford_nor(self) -> String { self.collect_oxford(Conjunction::Nor) }
+
+	#[inline]
+	/// # Collect Oxford (or).
+	///
+	/// This is equivalent to calling `collect_oxford(Conjunction::Or)`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::CollectOxford;
+	///
+	/// let out = (1..=3).map(|n| n.to_string()).collect_oxford_or();
+	/// assert_eq!(out, "1, 2, or 3");
+	/// ```
+	fn collect_oxford_or(self) -> String { self.collect_oxford(Conjunction::Or) }
+}
+
+impl<I> CollectOxford for I
+where I: Iterator, I::Item: AsRef<str> {}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::OxfordJoin;
+	use crate::tests::{
+		cross_check,
+		FRUIT,
+	};
+	use alloc::{
+		format,
+		string::ToString,
+	};
+
+	#[test]
+	fn t_collect_oxford() {
+		cross_check(|set, c| set.iter().collect_oxford(c));
+
+		// Shorthands.
+		for len in 0..=FRUIT.len() {
+			let set = &FRUIT[..len];
+			assert_eq!(set.iter().collect_oxford_and(), set.oxford_and());
+			assert_eq!(set.iter().collect_oxford_and_or(), set.oxford_and_or());
+			assert_eq!(set.iter().collect_oxford_nor(), set.oxford_nor());
+			assert_eq!(set.iter().collect_oxford_or(), set.oxford_or());
+		}
+
+		// Filter/map chains.
+		assert_eq!(
+			FRUIT.iter()
+				.filter(|s| s.len() < 7)
+				.map(|s| s.to_lowercase())
+				.collect_oxford_or(),
+			"apples or dates",
+		);
+		assert_eq!(
+			(1..=10_u8)
+				.filter(|n| n % 3 == 0)
+				.map(|n| format!("#{n}"))
+				.collect_oxford_and(),
+			"#3, #6, and #9",
+		);
+		assert_eq!(
+			core::iter::empty::<&str>().collect_oxford_and(),
+			"",
+		);
+		assert_eq!(
+			core::iter::once(1).map(|n| n.to_string()).collect_oxford_and(),
+			"1",
+		);
+	}
+}
@@ -0,0 +1,77 @@
+/*!
+# Oxford Join: Errors.
+*/
+
+use core::fmt;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Inline Error.
+///
+/// This is returned by [`Conjunction::try_inline`](crate::Conjunction::try_inline)
+/// when a custom conjunction contains a character that would break
+/// single-line output.
+pub struct InlineError {
+	/// # Offending Character.
+	chr: char,
+
+	/// # Byte Index.
+	idx: usize,
+}
+
+impl fmt::Display for InlineError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"the conjunction contains a disallowed {} at byte {}",
+			match self.chr {
+				'\n' => "line feed",
+				'\r' => "carriage return",
+				_ => "tab",
+			},
+			self.idx,
+		)
+	}
+}
+
+impl core::error::Error for InlineError {}
+
+impl InlineError {
+	/// # New.
+	pub(crate) const fn new(chr: char, idx: usize) -> Self { Self { chr, idx } }
+
+	#[must_use]
+	/// # Character.
+	///
+	/// Return the offending character: `'\n'`, `'\r'`, or `'\t'`.
+	pub const fn char(&self) -> char { self.chr }
+
+	#[must_use]
+	/// # Index.
+	///
+	/// Return the byte index of the offending character within the original
+	/// (untrimmed) string.
+	pub const fn index(&self) -> usize { self.idx }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_inline_error() {
+		for (chr, idx, expected) in [
+			('\n', 3, "the conjunction contains a disallowed line feed at byte 3"),
+			('\r', 0, "the conjunction contains a disallowed carriage return at byte 0"),
+			('\t', 5, "the conjunction contains a disallowed tab at byte 5"),
+		] {
+			let err = InlineError::new(chr, idx);
+			assert_eq!(err.char(), chr);
+			assert_eq!(err.index(), idx);
+			assert_eq!(alloc::format!("{err}"), expected);
+		}
+	}
+}
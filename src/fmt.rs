@@ -2,6 +2,8 @@
 # Oxford Join: Format (Display) Wrappers.
 */
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use crate::Conjunction;
 use core::{
 	cell::Cell,
@@ -10,6 +12,14 @@ use core::{
 
 
 
+#[cfg(feature = "alloc")]
+/// # Marker Callback.
+///
+/// This is used by [`OxfordJoinFmt::numbered`].
+type Marker = fn(usize) -> String;
+
+
+
 /// # [`Display`](fmt::Display)-Based Join Wrapper.
 ///
 /// This wrapper provides a symmetrical — _non-Oxford!_ — alternative to
@@ -64,7 +74,7 @@ where <I as Iterator>::Item: fmt::Display {
 	iter: Cell<Option<I>>,
 
 	/// # The Glue.
-	glue: &'a str,
+	glue: Glue<'a>,
 }
 
 impl<'a, I: Iterator> JoinFmt<'a, I>
@@ -89,8 +99,115 @@ where <I as Iterator>::Item: fmt::Display {
 	pub const fn new(iter: I, glue: &'a str) -> Self {
 		Self {
 			iter: Cell::new(Some(iter)),
-			glue,
+			glue: Glue::Str(glue),
+		}
+	}
+
+	#[inline]
+	/// # Join (Char).
+	///
+	/// Same as [`JoinFmt::new`], but for single-character separators.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::JoinFmt;
+	///
+	/// let set = ["one", "two", "three"];
+	/// assert_eq!(
+	///     format!("{}", JoinFmt::with_char(set.iter(), ',')),
+	///     "one,two,three",
+	/// );
+	/// ```
+	pub const fn with_char(iter: I, sep: char) -> Self {
+		Self {
+			iter: Cell::new(Some(iter)),
+			glue: Glue::Char(sep),
+		}
+	}
+}
+
+impl<I: ExactSizeIterator> JoinFmt<'_, I>
+where <I as Iterator>::Item: fmt::Display {
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the number of items left to join, or zero if the wrapper has
+	/// already been formatted.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::JoinFmt;
+	///
+	/// let set = ["one", "two", "three"];
+	/// let wrapped = JoinFmt::new(set.iter(), ", ");
+	/// assert_eq!(wrapped.len(), 3);
+	///
+	/// let _ = wrapped.to_string();
+	/// assert_eq!(wrapped.len(), 0);
+	/// ```
+	pub fn len(&self) -> usize {
+		let iter = self.iter.take();
+		let len = iter.as_ref().map_or(0, ExactSizeIterator::len);
+		self.iter.set(iter);
+		len
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Is Empty?
+	///
+	/// Returns `true` if there is nothing (left) to join.
+	pub fn is_empty(&self) -> bool { self.len() == 0 }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> JoinFmt<'_, I>
+where
+	I: Clone + ExactSizeIterator,
+	<I as Iterator>::Item: fmt::Display + AsRef<str> {
+	#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+	#[must_use]
+	/// # To String (Sized).
+	///
+	/// Collect the join into a `String`, same as `to_string`, but allocating
+	/// exactly once. The total length is precomputed using a cloned copy of
+	/// the iterator, an optimization only possible for string-like items.
+	///
+	/// As with formatting, the iterator is consumed; if the wrapper has
+	/// already been formatted, an empty string is returned.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::JoinFmt;
+	///
+	/// let set = ["one", "two", "three"];
+	/// let out = JoinFmt::new(set.iter(), ", ").to_string_sized();
+	/// assert_eq!(out, "one, two, three");
+	/// assert_eq!(out.capacity(), out.len());
+	/// ```
+	pub fn to_string_sized(self) -> String {
+		let Some(iter) = self.iter.into_inner() else { return String::new(); };
+		let glue = match self.glue {
+			Glue::Str(s) => s.len(),
+			Glue::Char(c) => c.len_utf8(),
+		};
+		let len = iter.clone().map(|v| v.as_ref().len()).sum::<usize>() +
+			glue * iter.len().saturating_sub(1);
+
+		let mut out = String::with_capacity(len);
+		for (idx, v) in iter.enumerate() {
+			if idx != 0 {
+				match self.glue {
+					Glue::Str(s) => out.push_str(s),
+					Glue::Char(c) => out.push(c),
+				}
+			}
+			out.push_str(v.as_ref());
 		}
+		out
 	}
 }
 
@@ -104,7 +221,7 @@ where <I as Iterator>::Item: fmt::Display {
 		let mut iter = self.iter.take().ok_or(fmt::Error)?;
 
 		// If the glue is empty, just run through everything in one go.
-		if self.glue.is_empty() {
+		if matches!(self.glue, Glue::Str("")) {
 			for v in iter { <I::Item as fmt::Display>::fmt(&v, f)?; }
 		}
 		// Otherwise start with the first first, then loop through the rest,
@@ -114,7 +231,10 @@ where <I as Iterator>::Item: fmt::Display {
 
 			// Finish it!
 			for v in iter {
-				f.write_str(self.glue)?;
+				match self.glue {
+					Glue::Str(s) => f.write_str(s)?,
+					Glue::Char(c) => fmt::Write::write_char(f, c)?,
+				}
 				<I::Item as fmt::Display>::fmt(&v, f)?;
 			}
 		}
@@ -125,6 +245,20 @@ where <I as Iterator>::Item: fmt::Display {
 
 
 
+#[derive(Clone, Copy)]
+/// # Join Glue.
+///
+/// The separator used by [`JoinFmt`].
+enum Glue<'a> {
+	/// # String.
+	Str(&'a str),
+
+	/// # Character.
+	Char(char),
+}
+
+
+
 /// # [`Display`](fmt::Display)-Based Oxford Join Wrapper.
 ///
 /// This struct offers a [`Display`](fmt::Display)-based alternative to the
@@ -160,37 +294,183 @@ where <I as Iterator>::Item: fmt::Display {
 ///     "Apples, Oranges, and/or Bananas",
 /// );
 /// ```
+///
+/// ## Alternate Layout
+///
+/// When formatted with the alternate flag — `{:#}` — each item is instead
+/// written on its own dash-bulleted line, with the separators at the ends
+/// of the lines and the conjunction at the start of the last.
+///
+/// Count prefixes, if any, get a line of their own; correlatives are
+/// omitted.
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordJoinFmt};
+///
+/// let set = ["Apples", "Oranges", "Bananas"];
+/// assert_eq!(
+///     format!("{:#}", OxfordJoinFmt::new(&set, Conjunction::And)),
+///     "- Apples,\n- Oranges,\n- and Bananas",
+/// );
+/// ```
 pub struct OxfordJoinFmt<'a, T: fmt::Display> {
 	/// # The Set.
 	inner: &'a [T],
 
 	/// # The Glue.
 	glue: Conjunction<'a>,
+
+	/// # The Separator.
+	sep: &'a str,
+
+	/// # Correlatives.
+	correlative: bool,
+
+	/// # Count Label.
+	count: Option<&'a str>,
+
+	/// # Trailer.
+	trailer: &'a str,
+
+	/// # Empty Fallback.
+	empty: &'a str,
+
+	/// # Non-Breaking Space.
+	nbsp: bool,
+
+	#[cfg(feature = "alloc")]
+	/// # Numbering (Start, Marker).
+	numbered: Option<(usize, Marker)>,
 }
 
 impl<T: fmt::Display> fmt::Display for OxfordJoinFmt<'_, T> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.fmt_join(f)?;
+		if self.trailer.is_empty() { Ok(()) }
+		else { f.write_str(self.trailer) }
+	}
+}
+
+impl<T: fmt::Display> OxfordJoinFmt<'_, T> {
+	/// # Write Join.
+	///
+	/// Write everything but the trailer.
+	fn fmt_join(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		use core::cmp::Ordering;
 
+		// Empty sets might have a fallback.
+		if self.inner.is_empty() && ! self.empty.is_empty() {
+			return f.write_str(self.empty);
+		}
+
+		// Pretty output is its own thing.
+		if f.alternate() { return self.fmt_pretty(f); }
+
+		// Start with the count, if desired.
+		if let Some(label) = self.count {
+			if self.inner.is_empty() { return write!(f, "0 {label}"); }
+			write!(f, "{} {label}: ", self.inner.len())?;
+		}
+
 		// Split off the last part, or quit because the set is empty.
 		if let Some((last, rest)) = self.inner.split_last() {
 			// If last is all we have, it's all we print!
 			match rest.len().cmp(&1) {
 				// Last is all there is.
-				Ordering::Less => write!(f, "{last}"),
+				Ordering::Less => self.fmt_item(f, 0, last),
 
 				// Just one thing.
-				Ordering::Equal => write!(f, "{} {} {last}", rest[0], self.glue),
+				Ordering::Equal => {
+					if self.correlative {
+						if let Some(p) = self.glue.correlative() { write!(f, "{p} ")?; }
+					}
+					self.fmt_item(f, 0, &rest[0])?;
+					let glue = glue_for(self.glue, last);
+					let [a, b, c] = glue.pair_parts();
+					write!(f, "{a}{b}{}", self.space(c))?;
+					self.fmt_item(f, 1, last)
+				},
 
 				// Many things.
 				Ordering::Greater => {
-					for v in rest { write!(f, "{v}, ")?; }
-					write!(f, "{} {last}", self.glue)
+					if self.correlative {
+						if let Some(p) = self.glue.list_correlative() { write!(f, "{p} ")?; }
+					}
+					self.fmt_item(f, 0, &rest[0])?;
+					for (idx, v) in rest.iter().enumerate().skip(1) {
+						f.write_str(self.sep)?;
+						self.fmt_item(f, idx, v)?;
+					}
+					let glue = glue_for(self.glue, last);
+					let [a, b, c] = glue.list_parts(self.sep);
+					write!(f, "{a}{b}{}", self.space(c))?;
+					self.fmt_item(f, rest.len(), last)
 				},
 			}
 		}
 		else { Ok(()) }
 	}
+
+	/// # Write Join (Pretty).
+	///
+	/// Write the set as a bulleted list, one item per line.
+	fn fmt_pretty(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Start with the count, if desired.
+		if let Some(label) = self.count {
+			if self.inner.is_empty() { return write!(f, "0 {label}"); }
+			writeln!(f, "{} {label}:", self.inner.len())?;
+		}
+
+		let Some((last, rest)) = self.inner.split_last() else { return Ok(()); };
+		let glue = glue_for(self.glue, last);
+		let [a, b, c] =
+			if rest.len() == 1 { glue.pair_parts() }
+			else { glue.list_parts(self.sep) };
+
+		// Everything but the last gets a separator and line break.
+		for (idx, v) in rest.iter().enumerate() {
+			f.write_str("- ")?;
+			self.fmt_item(f, idx, v)?;
+			let sep = if idx + 1 == rest.len() { a } else { self.sep };
+			writeln!(f, "{}", sep.trim_end())?;
+		}
+
+		// The last gets the conjunction, if there was a first.
+		f.write_str("- ")?;
+		let b = b.trim_start();
+		if ! rest.is_empty() && ! b.is_empty() { write!(f, "{b}{c}")?; }
+		self.fmt_item(f, rest.len(), last)
+	}
+
+	/// # Last Space.
+	///
+	/// Return the whitespace to use between the conjunction and the last
+	/// item.
+	const fn space<'b>(&self, space: &'b str) -> &'b str {
+		if self.nbsp && space.len() == 1 && space.as_bytes()[0] == b' ' { "\u{a0}" }
+		else { space }
+	}
+
+	#[cfg(feature = "alloc")]
+	/// # Write Item.
+	///
+	/// Write the item at (zero-based) position `idx`, preceded by its marker
+	/// if numbered.
+	fn fmt_item(&self, f: &mut fmt::Formatter<'_>, idx: usize, v: &T) -> fmt::Result {
+		if let Some((start, marker)) = self.numbered {
+			f.write_str(&marker(start.saturating_add(idx)))?;
+		}
+		write!(f, "{v}")
+	}
+
+	#[cfg(not(feature = "alloc"))]
+	#[expect(clippy::unused_self, reason = "Numbering requires alloc.")]
+	/// # Write Item.
+	///
+	/// Write the item. (Numbering requires the `alloc` feature.)
+	fn fmt_item(&self, f: &mut fmt::Formatter<'_>, _idx: usize, v: &T) -> fmt::Result {
+		write!(f, "{v}")
+	}
 }
 
 impl<'a, T: fmt::Display> OxfordJoinFmt<'a, T> {
@@ -211,7 +491,270 @@ impl<'a, T: fmt::Display> OxfordJoinFmt<'a, T> {
 	/// );
 	/// ```
 	pub const fn new(set: &'a [T], glue: Conjunction<'a>) -> Self {
-		Self { inner: set, glue }
+		Self {
+			inner: set,
+			glue,
+			sep: ", ",
+			correlative: false,
+			count: None,
+			trailer: "",
+			empty: "",
+			nbsp: false,
+			#[cfg(feature = "alloc")]
+			numbered: None,
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+	#[inline]
+	/// # Oxford Join (Numbered).
+	///
+	/// Same as [`OxfordJoinFmt::new`], but with each item preceded by a
+	/// marker, the string returned by `fmt_num` for the item's position.
+	/// Positions begin at `start`, usually `0` or `1`.
+	///
+	/// The markers are written as-is, so should include any trailing
+	/// punctuation and whitespace, e.g. `"1. "` or `"a) "`. The conjunction
+	/// rules are otherwise unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoinFmt};
+	///
+	/// let set = ["Apples", "Bananas", "Carrots"];
+	/// assert_eq!(
+	///     OxfordJoinFmt::numbered(&set, Conjunction::And, 1, |n| format!("{n}. ")).to_string(),
+	///     "1. Apples, 2. Bananas, and 3. Carrots",
+	/// );
+	///
+	/// /// # Alphabetic Markers.
+	/// fn alpha(n: usize) -> String {
+	///     let chr = u8::try_from(n % 26).map_or('?', |n| char::from(b'a' + n));
+	///     format!("{chr}) ")
+	/// }
+	/// assert_eq!(
+	///     OxfordJoinFmt::numbered(&set, Conjunction::Or, 0, alpha).to_string(),
+	///     "a) Apples, b) Bananas, or c) Carrots",
+	/// );
+	/// ```
+	pub const fn numbered(
+		set: &'a [T],
+		glue: Conjunction<'a>,
+		start: usize,
+		fmt_num: fn(usize) -> String,
+	) -> Self {
+		Self { numbered: Some((start, fmt_num)), ..Self::new(set, glue) }
+	}
+
+	#[inline]
+	/// # Oxford Join (With Fallback).
+	///
+	/// Same as [`OxfordJoinFmt::new`], but if the set is empty, `empty` is
+	/// written in its place, so callers needn't special-case zero.
+	///
+	/// The fallback takes precedence over any
+	/// [count prefix](OxfordJoinFmt::with_count_prefix), but any
+	/// [trailer](OxfordJoinFmt::with_trailer) is still appended after it.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoinFmt};
+	///
+	/// let set: [&str; 0] = [];
+	/// assert_eq!(
+	///     OxfordJoinFmt::new_or(&set, Conjunction::And, "nothing").to_string(),
+	///     "nothing",
+	/// );
+	///
+	/// // Non-empty sets are joined as usual.
+	/// let set = ["Apples", "Oranges"];
+	/// assert_eq!(
+	///     OxfordJoinFmt::new_or(&set, Conjunction::And, "nothing").to_string(),
+	///     "Apples and Oranges",
+	/// );
+	/// ```
+	pub const fn new_or(set: &'a [T], glue: Conjunction<'a>, empty: &'a str) -> Self {
+		Self { empty, ..Self::new(set, glue) }
+	}
+
+	#[inline]
+	#[must_use]
+	/// # With Separator.
+	///
+	/// Replace the separator used between entries of sets with three or more
+	/// items, just like [`JoinStyle::with_separator`](crate::JoinStyle::with_separator).
+	/// The value is used as-is, so should include any trailing whitespace,
+	/// e.g. `"; "`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoinFmt};
+	///
+	/// let set = ["Paris, France", "Rome, Italy", "Oslo, Norway"];
+	/// assert_eq!(
+	///     OxfordJoinFmt::new(&set, Conjunction::And).with_separator("; ").to_string(),
+	///     "Paris, France; Rome, Italy; and Oslo, Norway",
+	/// );
+	/// ```
+	pub const fn with_separator(self, sep: &'a str) -> Self {
+		Self { sep, ..self }
+	}
+
+	#[inline]
+	#[must_use]
+	/// # With Correlatives.
+	///
+	/// Introduce the join with the conjunction's correlative, if any. Pairs
+	/// use the [two-item form](Conjunction::correlative) — `"both"`,
+	/// `"either"`, `"neither"` — while larger sets use the
+	/// [list form](Conjunction::list_correlative) — `"all of"`, `"any of"`,
+	/// `"none of"`.
+	///
+	/// Single-item sets, and conjunctions without correlatives, are unaffected.
+	///
+	/// (Note that [`JoinStyle::with_correlative`](crate::JoinStyle::with_correlative)
+	/// only affects pairs.)
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoinFmt};
+	///
+	/// let set = ["Apples", "Bananas", "Carrots"];
+	/// assert_eq!(
+	///     OxfordJoinFmt::new(&set[..2], Conjunction::Nor).with_correlative().to_string(),
+	///     "neither Apples nor Bananas",
+	/// );
+	/// assert_eq!(
+	///     OxfordJoinFmt::new(&set, Conjunction::Or).with_correlative().to_string(),
+	///     "any of Apples, Bananas, or Carrots",
+	/// );
+	/// ```
+	pub const fn with_correlative(self) -> Self {
+		Self { correlative: true, ..self }
+	}
+
+	#[inline]
+	#[must_use]
+	/// # With Count Prefix.
+	///
+	/// Prefix the join with the number of items and a label, rendering
+	/// `"{n} {label}: {join}"`, or just `"0 {label}"` for empty sets.
+	///
+	/// Pluralization is left to the caller.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoinFmt};
+	///
+	/// let set = ["Apples", "Bananas", "Carrots"];
+	/// assert_eq!(
+	///     OxfordJoinFmt::new(&set, Conjunction::And).with_count_prefix("fruits").to_string(),
+	///     "3 fruits: Apples, Bananas, and Carrots",
+	/// );
+	/// assert_eq!(
+	///     OxfordJoinFmt::new(&set[..1], Conjunction::And).with_count_prefix("fruit").to_string(),
+	///     "1 fruit: Apples",
+	/// );
+	/// assert_eq!(
+	///     OxfordJoinFmt::new(&set[..0], Conjunction::And).with_count_prefix("fruits").to_string(),
+	///     "0 fruits",
+	/// );
+	/// ```
+	pub const fn with_count_prefix(self, label: &'a str) -> Self {
+		Self { count: Some(label), ..self }
+	}
+
+	#[inline]
+	#[must_use]
+	/// # With Non-Breaking Space.
+	///
+	/// Replace the space between the conjunction and the last item with a
+	/// non-breaking one (`U+00A0`), so typeset output never wraps right
+	/// before the last word, e.g. `"a, b, and\u{a0}c"`.
+	///
+	/// Only that one space is affected. [`Conjunction::OtherRaw`]
+	/// conjunctions, which have no such space, are unaffected.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoinFmt};
+	///
+	/// let set = ["Apples", "Bananas", "Carrots"];
+	/// assert_eq!(
+	///     OxfordJoinFmt::new(&set, Conjunction::And).with_nbsp().to_string(),
+	///     "Apples, Bananas, and\u{a0}Carrots",
+	/// );
+	/// ```
+	pub const fn with_nbsp(self) -> Self {
+		Self { nbsp: true, ..self }
+	}
+
+	#[inline]
+	#[must_use]
+	/// # With Trailer.
+	///
+	/// Append arbitrary text after the join, in the same
+	/// [`Display`](fmt::Display) pass. The value is used as-is, so should
+	/// include any leading whitespace.
+	///
+	/// The trailer is written even if the set is empty or singular. (An
+	/// empty trailer writes nothing.)
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoinFmt};
+	///
+	/// let loaded = ["a", "b", "c"];
+	/// let errors = 2;
+	/// let trailer = format!(" (and {errors} errors)");
+	/// assert_eq!(
+	///     OxfordJoinFmt::new(&loaded, Conjunction::And).with_trailer(&trailer).to_string(),
+	///     "a, b, and c (and 2 errors)",
+	/// );
+	/// ```
+	pub const fn with_trailer(self, trailer: &'a str) -> Self {
+		Self { trailer, ..self }
+	}
+
+	#[inline]
+	/// # Oxford Join (Non-Empty).
+	///
+	/// Same as [`OxfordJoinFmt::new`], but for arrays that are guaranteed —
+	/// at compile time — to contain at least one item, useful for lists that
+	/// should never come up empty.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoinFmt};
+	///
+	/// let set = ["Apples", "Oranges"];
+	/// assert_eq!(
+	///     OxfordJoinFmt::non_empty(&set, Conjunction::And).to_string(),
+	///     "Apples and Oranges",
+	/// );
+	/// ```
+	///
+	/// Empty arrays, on the other hand, won't compile:
+	///
+	/// ```compile_fail
+	/// use oxford_join::{Conjunction, OxfordJoinFmt};
+	///
+	/// let set: [&str; 0] = [];
+	/// let nope = OxfordJoinFmt::non_empty(&set, Conjunction::And);
+	/// ```
+	pub const fn non_empty<const N: usize>(set: &'a [T; N], glue: Conjunction<'a>)
+	-> Self {
+		const { assert!(0 < N, "The set cannot be empty."); }
+		Self::new(set, glue)
 	}
 
 	#[inline]
@@ -289,6 +832,290 @@ impl<'a, T: fmt::Display> OxfordJoinFmt<'a, T> {
 	/// );
 	/// ```
 	pub const fn or(set: &'a [T]) -> Self { Self::new(set, Conjunction::Or) }
+
+	#[inline]
+	/// # Question.
+	///
+	/// This is equivalent to passing [`Conjunction::Or`] to
+	/// [`OxfordJoinFmt::new`], with a `"?"` [trailer](OxfordJoinFmt::with_trailer).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::OxfordJoinFmt;
+	///
+	/// let set = ["Apples", "Bananas", "Carrots"];
+	/// assert_eq!(
+	///     format!("Would you like {}", OxfordJoinFmt::question(&set)),
+	///     "Would you like Apples, Bananas, or Carrots?",
+	/// );
+	/// ```
+	pub const fn question(set: &'a [T]) -> Self { Self::or(set).with_trailer("?") }
+
+	#[inline]
+	/// # Statement.
+	///
+	/// This is equivalent to passing [`Conjunction::And`] to
+	/// [`OxfordJoinFmt::new`], with a `"."` [trailer](OxfordJoinFmt::with_trailer).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::OxfordJoinFmt;
+	///
+	/// let set = ["Apples", "Bananas", "Carrots"];
+	/// assert_eq!(
+	///     format!("We have {}", OxfordJoinFmt::statement(&set)),
+	///     "We have Apples, Bananas, and Carrots.",
+	/// );
+	/// ```
+	pub const fn statement(set: &'a [T]) -> Self { Self::and(set).with_trailer(".") }
+
+	#[inline]
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the number of items in the set, handy for verb agreement and
+	/// the like.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::OxfordJoinFmt;
+	///
+	/// let set = ["Apples", "Oranges"];
+	/// let fmt = OxfordJoinFmt::and(&set);
+	/// let verb = if fmt.len() == 1 { "is" } else { "are" };
+	/// assert_eq!(format!("{fmt} {verb} ripe."), "Apples and Oranges are ripe.");
+	/// ```
+	pub const fn len(&self) -> usize { self.inner.len() }
+
+	#[inline]
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// Returns `true` if the set is empty.
+	pub const fn is_empty(&self) -> bool { self.inner.is_empty() }
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # [`Display`](fmt::Display)-Based Oxford Pair.
+///
+/// This is a specialized version of [`OxfordJoinFmt`] for the extremely
+/// common two-item case, working directly with the two values — which can be
+/// of different types — without the need for a slice.
+///
+/// ## Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use oxford_join::{Conjunction, OxfordJoin, OxfordPair};
+///
+/// assert_eq!(
+///     format!("{}", OxfordPair::new("Yes", "No", Conjunction::Or)),
+///     "Yes or No",
+/// );
+///
+/// // The values needn't be strings, or even match.
+/// assert_eq!(
+///     OxfordPair::new('A', 1, Conjunction::Ampersand).to_string(),
+///     "A & 1",
+/// );
+///
+/// // Same as the trait.
+/// assert_eq!(
+///     OxfordPair::new("R", "D", Conjunction::Plus).to_string(),
+///     ["R", "D"].oxford_join(Conjunction::Plus),
+/// );
+/// # }
+/// ```
+pub struct OxfordPair<'a, A: fmt::Display, B: fmt::Display> {
+	/// # First.
+	a: A,
+
+	/// # Last.
+	b: B,
+
+	/// # The Glue.
+	glue: Conjunction<'a>,
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for OxfordPair<'_, A, B> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let glue = glue_for(self.glue, &self.b);
+		let [x, y, z] = glue.pair_parts();
+		write!(f, "{}{x}{y}{z}{}", self.a, self.b)
+	}
+}
+
+impl<'a, A: fmt::Display, B: fmt::Display> OxfordPair<'a, A, B> {
+	#[must_use]
+	/// # New.
+	///
+	/// Wrap the two values and conjunction.
+	pub const fn new(a: A, b: B, glue: Conjunction<'a>) -> Self {
+		Self { a, b, glue }
+	}
+}
+
+
+
+/// # [`Display`](fmt::Display)-Based Oxford Join Wrapper (Strings).
+///
+/// This is a version of [`OxfordJoinFmt`] for sets of `T: AsRef<str>` rather
+/// than `T: Display`, mirroring the bounds of the main
+/// [`OxfordJoin`](crate::OxfordJoin) trait. It is handy for string-like types
+/// that don't implement [`Display`](fmt::Display) themselves.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordJoinStrFmt};
+///
+/// // This is AsRef<str>, but not Display.
+/// struct Name(String);
+/// impl AsRef<str> for Name {
+///     fn as_ref(&self) -> &str { &self.0 }
+/// }
+///
+/// let set = [
+///     Name(String::from("Apples")),
+///     Name(String::from("Oranges")),
+///     Name(String::from("Bananas")),
+/// ];
+/// assert_eq!(
+///     format!("I like {}!", OxfordJoinStrFmt::new(&set, Conjunction::And)),
+///     "I like Apples, Oranges, and Bananas!",
+/// );
+/// ```
+pub struct OxfordJoinStrFmt<'a, T: AsRef<str>> {
+	/// # The Set.
+	inner: &'a [T],
+
+	/// # The Glue.
+	glue: Conjunction<'a>,
+
+	/// # The Separator.
+	sep: &'a str,
+}
+
+impl<T: AsRef<str>> fmt::Display for OxfordJoinStrFmt<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.inner {
+			[] => Ok(()),
+			[only] => f.write_str(only.as_ref()),
+			[first, mid @ .., last] => {
+				let last = last.as_ref();
+				let glue = self.glue.for_last(last);
+				f.write_str(first.as_ref())?;
+				for v in mid {
+					f.write_str(self.sep)?;
+					f.write_str(v.as_ref())?;
+				}
+				let parts =
+					if mid.is_empty() { glue.pair_parts() }
+					else { glue.list_parts(self.sep) };
+				for p in parts { f.write_str(p)?; }
+				f.write_str(last)
+			},
+		}
+	}
+}
+
+impl<'a, T: AsRef<str>> OxfordJoinStrFmt<'a, T> {
+	#[must_use]
+	/// # New.
+	///
+	/// Return a wrapper for the set with the desired conjunction.
+	pub const fn new(set: &'a [T], glue: Conjunction<'a>) -> Self {
+		Self { inner: set, glue, sep: ", " }
+	}
+
+	#[must_use]
+	/// # With Separator.
+	///
+	/// Replace the separator used between entries of sets with three or more
+	/// items, just like [`OxfordJoinFmt::with_separator`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoinStrFmt};
+	///
+	/// let set = ["Paris, France", "Rome, Italy", "Oslo, Norway"];
+	/// assert_eq!(
+	///     OxfordJoinStrFmt::new(&set, Conjunction::And).with_separator("; ").to_string(),
+	///     "Paris, France; Rome, Italy; and Oslo, Norway",
+	/// );
+	/// ```
+	pub const fn with_separator(self, sep: &'a str) -> Self {
+		Self { sep, ..self }
+	}
+
+	#[inline]
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the number of items in the set.
+	pub const fn len(&self) -> usize { self.inner.len() }
+
+	#[inline]
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// Returns `true` if the set is empty.
+	pub const fn is_empty(&self) -> bool { self.inner.is_empty() }
+}
+
+
+
+/// # Dynamic Prefix Buffer.
+///
+/// This holds the leading bytes of a [`Display`](fmt::Display) value, enough
+/// for [`Conjunction::Dynamic`] selectors to work with.
+struct Prefix {
+	/// # Buffer.
+	buf: [u8; 32],
+
+	/// # Length.
+	len: usize,
+}
+
+impl fmt::Write for Prefix {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		for c in s.chars() {
+			let end = self.len + c.len_utf8();
+			// Stop once we're full; erroring out saves formatting the rest.
+			if self.buf.len() < end { return Err(fmt::Error); }
+			c.encode_utf8(&mut self.buf[self.len..end]);
+			self.len = end;
+		}
+		Ok(())
+	}
+}
+
+impl Prefix {
+	/// # As Str.
+	fn as_str(&self) -> &str {
+		// Only whole chars are ever written.
+		core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+	}
+}
+
+/// # Resolve Glue.
+///
+/// Resolve [`Conjunction::Dynamic`] glue against (the start of) the last
+/// item; other conjunctions are returned as-is.
+fn glue_for<'a, T: fmt::Display>(glue: Conjunction<'a>, last: &T) -> Conjunction<'a> {
+	if matches!(glue, Conjunction::Dynamic(_)) {
+		let mut prefix = Prefix { buf: [0; 32], len: 0 };
+		// An error here just means the value was truncated.
+		let _res = fmt::Write::write_fmt(&mut prefix, format_args!("{last}"));
+		glue.for_last(prefix.as_str())
+	}
+	else { glue }
 }
 
 
@@ -297,6 +1124,338 @@ impl<'a, T: fmt::Display> OxfordJoinFmt<'a, T> {
 mod test {
 	use super::*;
 	use alloc::format;
+	use brunch as _;
+
+	#[test]
+	fn t_oxford_pair() {
+		const SET: [&str; 2] = ["Apples", "Bananas"];
+		for c in Conjunction::ALL.into_iter().chain([
+			Conjunction::Other("und"),
+			Conjunction::OtherRaw { pair: "→", list: " → " },
+			Conjunction::Dynamic(|s| if s.starts_with('B') { "&" } else { "+" }),
+		]) {
+			let pair = OxfordPair::new(SET[0], SET[1], c);
+			assert_eq!(format!("{pair}"), format!("{}", OxfordJoinFmt::new(&SET, c)));
+		}
+
+		assert_eq!(
+			format!("{}", OxfordPair::new("A", "B", Conjunction::OtherRaw { pair: "→", list: " → " })),
+			"A→B",
+		);
+		assert_eq!(
+			format!("{}", OxfordPair::new("Juan", "Inés", Conjunction::Dynamic(|s| if s.starts_with('I') { "e" } else { "y" }))),
+			"Juan e Inés",
+		);
+
+		// Symbols are spaced the same as words.
+		assert_eq!(format!("{}", OxfordPair::new(1, 2, Conjunction::Plus)), "1 + 2");
+		assert_eq!(format!("{}", OxfordPair::new(1, 2, Conjunction::Ampersand)), "1 & 2");
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn t_oxford_str_fmt() {
+		/// # AsRef-Only Type.
+		struct Name(&'static str);
+
+		impl AsRef<str> for Name {
+			fn as_ref(&self) -> &str { self.0 }
+		}
+
+		crate::tests::cross_check(|set, c| {
+			let names: alloc::vec::Vec<Name> = set.iter().copied().map(Name).collect();
+			assert_eq!(
+				format!("{}", OxfordJoinStrFmt::new(&names, c).with_separator("; ")),
+				format!("{}", OxfordJoinFmt::new(set, c).with_separator("; ")),
+			);
+			format!("{}", OxfordJoinStrFmt::new(&names, c))
+		});
+	}
+
+	#[test]
+	fn t_oxford_count_prefix() {
+		const SET: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		for (len, label, expected) in [
+			(0, "items", "0 items"),
+			(1, "item", "1 item: Apples"),
+			(2, "items", "2 items: Apples or Bananas"),
+			(3, "items", "3 items: Apples, Bananas, or Carrots"),
+		] {
+			let wrapper = OxfordJoinFmt::new(&SET[..len], Conjunction::Or).with_count_prefix(label);
+			assert_eq!(format!("{wrapper}"), expected);
+		}
+
+		// The count comes before any correlatives.
+		assert_eq!(
+			format!(
+				"{}",
+				OxfordJoinFmt::new(&SET[..2], Conjunction::Nor)
+					.with_correlative()
+					.with_count_prefix("fruits")
+			),
+			"2 fruits: neither Apples nor Bananas",
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn t_oxford_numbered() {
+		/// # Alphabetic Markers.
+		fn alpha(n: usize) -> String {
+			let chr = u8::try_from(n).map_or('?', |n| char::from(b'a' + n));
+			format!("{chr}) ")
+		}
+
+		/// # Numeric Markers.
+		fn num(n: usize) -> String { format!("{n}. ") }
+
+		const SET: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		for (len, expected_a, expected_n) in [
+			(0, "", ""),
+			(1, "a) Apples", "1. Apples"),
+			(2, "a) Apples or b) Bananas", "1. Apples or 2. Bananas"),
+			(3, "a) Apples, b) Bananas, or c) Carrots", "1. Apples, 2. Bananas, or 3. Carrots"),
+		] {
+			let wrapper = OxfordJoinFmt::numbered(&SET[..len], Conjunction::Or, 0, alpha);
+			assert_eq!(format!("{wrapper}"), expected_a);
+
+			let wrapper = OxfordJoinFmt::numbered(&SET[..len], Conjunction::Or, 1, num);
+			assert_eq!(format!("{wrapper}"), expected_n);
+		}
+
+		// Zero-based numbering, with other options.
+		assert_eq!(
+			format!(
+				"{}",
+				OxfordJoinFmt::numbered(&SET, Conjunction::And, 0, num)
+					.with_separator("; ")
+					.with_correlative()
+					.with_trailer("."),
+			),
+			"all of 0. Apples; 1. Bananas; and 2. Carrots.",
+		);
+	}
+
+	#[test]
+	fn t_oxford_nbsp() {
+		const SET: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		for (len, expected) in [
+			(0, &b""[..]),
+			(1, b"Apples"),
+			(2, b"Apples and\xc2\xa0Bananas"),
+			(3, b"Apples, Bananas, and\xc2\xa0Carrots"),
+		] {
+			let wrapper = OxfordJoinFmt::new(&SET[..len], Conjunction::And).with_nbsp();
+			assert_eq!(format!("{wrapper}").as_bytes(), expected);
+		}
+
+		// Other options should be unaffected.
+		assert_eq!(
+			format!(
+				"{}",
+				OxfordJoinFmt::new(&SET, Conjunction::Nor)
+					.with_correlative()
+					.with_separator("; ")
+					.with_nbsp(),
+			),
+			"none of Apples; Bananas; nor\u{a0}Carrots",
+		);
+
+		// Raw conjunctions have no space to replace.
+		let glue = Conjunction::OtherRaw { pair: "–", list: ",–" };
+		assert_eq!(
+			format!("{}", OxfordJoinFmt::new(&SET[..2], glue).with_nbsp()),
+			"Apples–Bananas",
+		);
+	}
+
+	#[test]
+	fn t_oxford_question_statement() {
+		const SET: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		for (len, question, statement) in [
+			(0, "?", "."),
+			(1, "Apples?", "Apples."),
+			(2, "Apples or Bananas?", "Apples and Bananas."),
+			(3, "Apples, Bananas, or Carrots?", "Apples, Bananas, and Carrots."),
+		] {
+			assert_eq!(format!("{}", OxfordJoinFmt::question(&SET[..len])), question);
+			assert_eq!(format!("{}", OxfordJoinFmt::statement(&SET[..len])), statement);
+		}
+
+		// The trailer can still be swapped out.
+		assert_eq!(
+			format!("{}", OxfordJoinFmt::question(&SET).with_trailer("?!")),
+			"Apples, Bananas, or Carrots?!",
+		);
+	}
+
+	#[test]
+	fn t_oxford_trailer() {
+		const SET: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		for (len, expected) in [
+			(0, "…"),
+			(1, "Apples…"),
+			(2, "Apples and Bananas…"),
+			(3, "Apples, Bananas, and Carrots…"),
+		] {
+			let wrapper = OxfordJoinFmt::new(&SET[..len], Conjunction::And).with_trailer("…");
+			assert_eq!(format!("{wrapper}"), expected);
+
+			// Empty trailers are no trailers.
+			let wrapper = OxfordJoinFmt::new(&SET[..len], Conjunction::And).with_trailer("");
+			assert_eq!(format!("{wrapper}"), expected.trim_end_matches('…'));
+		}
+
+		// The trailer comes last, even with a count.
+		assert_eq!(
+			format!(
+				"{}",
+				OxfordJoinFmt::new(&SET[..0], Conjunction::And)
+					.with_count_prefix("fruits")
+					.with_trailer(" (and 2 errors)"),
+			),
+			"0 fruits (and 2 errors)",
+		);
+		assert_eq!(
+			format!(
+				"{}",
+				OxfordJoinFmt::new(&SET, Conjunction::Nor)
+					.with_correlative()
+					.with_trailer("!"),
+			),
+			"none of Apples, Bananas, nor Carrots!",
+		);
+	}
+
+	#[test]
+	fn t_oxford_separator() {
+		const SET: [&str; 4] = ["Apples", "Bananas", "Carrots", "Dates"];
+		for (len, expected) in [
+			(0, ""),
+			(1, "Apples"),
+			(2, "Apples or Bananas"),
+			(3, "Apples; Bananas; or Carrots"),
+			(4, "Apples; Bananas; Carrots; or Dates"),
+		] {
+			let wrapper = OxfordJoinFmt::new(&SET[..len], Conjunction::Or).with_separator("; ");
+			assert_eq!(format!("{wrapper}"), expected);
+		}
+
+		// Separators and correlatives can be combined.
+		assert_eq!(
+			format!(
+				"{}",
+				OxfordJoinFmt::new(&SET[..3], Conjunction::Nor)
+					.with_separator(" / ")
+					.with_correlative(),
+			),
+			"none of Apples / Bananas / nor Carrots",
+		);
+	}
+
+	#[test]
+	fn t_oxford_correlative() {
+		const SET: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		for (glue, two, three) in [
+			(Conjunction::And, "both Apples and Bananas", "all of Apples, Bananas, and Carrots"),
+			(Conjunction::Or, "either Apples or Bananas", "any of Apples, Bananas, or Carrots"),
+			(Conjunction::Nor, "neither Apples nor Bananas", "none of Apples, Bananas, nor Carrots"),
+			(Conjunction::Plus, "Apples + Bananas", "Apples, Bananas, + Carrots"),
+		] {
+			assert_eq!(format!("{}", OxfordJoinFmt::new(&SET[..1], glue).with_correlative()), "Apples");
+			assert_eq!(format!("{}", OxfordJoinFmt::new(&SET[..2], glue).with_correlative()), two);
+			assert_eq!(format!("{}", OxfordJoinFmt::new(&SET, glue).with_correlative()), three);
+		}
+	}
+
+	#[test]
+	fn t_oxford_new_or() {
+		const SET: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		for c in Conjunction::ALL {
+			for len in 0..=SET.len() {
+				let set = &SET[..len];
+				let out = format!("{}", OxfordJoinFmt::new_or(set, c, "nothing"));
+				if len == 0 { assert_eq!(out, "nothing"); }
+				else { assert_eq!(out, format!("{}", OxfordJoinFmt::new(set, c))); }
+			}
+		}
+
+		// Fallbacks beat counts, but not trailers.
+		let set: [&str; 0] = [];
+		assert_eq!(
+			format!(
+				"{}",
+				OxfordJoinFmt::new_or(&set, Conjunction::And, "no fruit")
+					.with_count_prefix("fruits")
+					.with_trailer("!"),
+			),
+			"no fruit!",
+		);
+
+		// Empty fallbacks are no fallbacks.
+		assert_eq!(
+			format!("{}", OxfordJoinFmt::new_or(&set, Conjunction::And, "").with_count_prefix("fruits")),
+			"0 fruits",
+		);
+	}
+
+	#[test]
+	fn t_oxford_pretty() {
+		const SET: [&str; 4] = ["a", "b", "c", "d"];
+		for (len, glue, expected) in [
+			(0, Conjunction::And, ""),
+			(1, Conjunction::And, "- a"),
+			(2, Conjunction::And, "- a\n- and b"),
+			(3, Conjunction::And, "- a,\n- b,\n- and c"),
+			(4, Conjunction::Or, "- a,\n- b,\n- c,\n- or d"),
+			(2, Conjunction::Comma, "- a,\n- b"),
+			(3, Conjunction::Comma, "- a,\n- b,\n- c"),
+			(3, Conjunction::OtherRaw { pair: "→", list: " ⇒ " }, "- a,\n- b\n- ⇒ c"),
+		] {
+			let wrapper = OxfordJoinFmt::new(&SET[..len], glue);
+			assert_eq!(format!("{wrapper:#}"), expected);
+
+			// The regular layout is unaffected.
+			assert_eq!(format!("{wrapper}"), format!("{}", OxfordJoinStrFmt::new(&SET[..len], glue)));
+		}
+
+		// Separators, counts, fallbacks, and trailers.
+		assert_eq!(
+			format!("{:#}", OxfordJoinFmt::new(&SET[..3], Conjunction::And).with_separator("; ")),
+			"- a;\n- b;\n- and c",
+		);
+		assert_eq!(
+			format!("{:#}", OxfordJoinFmt::new(&SET[..2], Conjunction::Nor).with_count_prefix("letters").with_correlative()),
+			"2 letters:\n- a\n- nor b",
+		);
+		assert_eq!(
+			format!("{:#}", OxfordJoinFmt::new_or(&SET[..0], Conjunction::And, "nothing").with_trailer("!")),
+			"nothing!",
+		);
+	}
+
+	#[test]
+	fn t_fmt_len() {
+		const SET: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		for len in 0..=SET.len() {
+			let set = &SET[..len];
+			let wrapper = OxfordJoinFmt::and(set);
+			assert_eq!(wrapper.len(), len);
+			assert_eq!(wrapper.is_empty(), len == 0);
+
+			let wrapper = OxfordJoinStrFmt::new(set, Conjunction::And);
+			assert_eq!(wrapper.len(), len);
+			assert_eq!(wrapper.is_empty(), len == 0);
+
+			// Iterators are drained by formatting.
+			let wrapper = JoinFmt::new(set.iter(), ", ");
+			assert_eq!(wrapper.len(), len);
+			assert_eq!(wrapper.is_empty(), len == 0);
+			let _ = format!("{wrapper}");
+			assert_eq!(wrapper.len(), 0);
+			assert!(wrapper.is_empty());
+		}
+	}
 
 	#[test]
 	fn t_join() {
@@ -318,5 +1477,41 @@ mod test {
 			format!("{}", JoinFmt::new(["hi", "ho"].iter(), "")),
 			"hiho",
 		);
+
+		// Chars work the same way as strings.
+		assert_eq!(
+			format!("{}", JoinFmt::with_char(["hi", "ho", "hum"].iter(), '·')),
+			"hi·ho·hum",
+		);
+		assert_eq!(
+			format!("{}", JoinFmt::with_char(core::iter::once("hi"), '-')),
+			"hi",
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn t_join_sized() {
+		use alloc::string::ToString;
+
+		const SET: [&str; 4] = ["Apples", "Bananas", "Carrots", "Dates"];
+		for len in 0..=SET.len() {
+			let set = &SET[..len];
+			for glue in ["", ", ", " · "] {
+				let out = JoinFmt::new(set.iter(), glue).to_string_sized();
+				assert_eq!(out, JoinFmt::new(set.iter(), glue).to_string());
+				assert_eq!(out, set.join(glue));
+				if ! out.is_empty() { assert_eq!(out.capacity(), out.len()); }
+			}
+
+			let out = JoinFmt::with_char(set.iter(), '·').to_string_sized();
+			assert_eq!(out, set.join("·"));
+			if ! out.is_empty() { assert_eq!(out.capacity(), out.len()); }
+		}
+
+		// Used up.
+		let wrapped = JoinFmt::new(SET.iter(), ", ");
+		assert_eq!(wrapped.to_string(), "Apples, Bananas, Carrots, Dates");
+		assert_eq!(wrapped.to_string_sized(), "");
 	}
 }
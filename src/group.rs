@@ -0,0 +1,88 @@
+/*!
+# Oxford Join: Grouped Joins.
+*/
+
+use alloc::{
+	format,
+	string::String,
+};
+use core::fmt;
+use crate::{
+	Conjunction,
+	OxfordJoin,
+};
+
+
+
+#[must_use]
+/// # Oxford Join (Grouped).
+///
+/// Join labeled groups of items, each rendered as `"{label} ({items})"` with
+/// the items Oxford-joined using `inner_glue`, and the groups themselves
+/// Oxford-joined using `outer_glue`.
+///
+/// Empty groups are rendered as the label alone.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, oxford_join_grouped};
+///
+/// let groups: [(&str, &[&str]); 3] = [
+///     ("red", &["Apple", "Cherry"]),
+///     ("green", &["Lime"]),
+///     ("yellow", &["Banana"]),
+/// ];
+/// assert_eq!(
+///     oxford_join_grouped(&groups, Conjunction::And, Conjunction::And),
+///     "red (Apple and Cherry), green (Lime), and yellow (Banana)",
+/// );
+/// ```
+pub fn oxford_join_grouped<K, T>(
+	groups: &[(K, &[T])],
+	outer_glue: Conjunction,
+	inner_glue: Conjunction,
+) -> String
+where K: fmt::Display, T: AsRef<str> {
+	outer_glue.oxford_join(groups.iter().map(|(label, set)|
+		if set.is_empty() { format!("{label}") }
+		else { format!("{label} ({})", set.oxford_join(inner_glue)) }
+	))
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_oxford_join_grouped() {
+		let groups: [(&str, &[&str]); 4] = [
+			("red", &["Apple", "Cherry", "Strawberry"]),
+			("green", &["Lime"]),
+			("yellow", &["Banana", "Lemon"]),
+			("blue", &[]),
+		];
+
+		for (len, expected) in [
+			(0, ""),
+			(1, "red (Apple, Cherry, and Strawberry)"),
+			(2, "red (Apple, Cherry, and Strawberry) or green (Lime)"),
+			(3, "red (Apple, Cherry, and Strawberry), green (Lime), or yellow (Banana and Lemon)"),
+			(4, "red (Apple, Cherry, and Strawberry), green (Lime), yellow (Banana and Lemon), or blue"),
+		] {
+			assert_eq!(
+				oxford_join_grouped(&groups[..len], Conjunction::Or, Conjunction::And),
+				expected,
+			);
+		}
+
+		// Numeric labels.
+		let groups: [(u8, &[&str]); 2] = [(1, &["a"]), (2, &["b", "c"])];
+		assert_eq!(
+			oxford_join_grouped(&groups, Conjunction::And, Conjunction::Plus),
+			"1 (a) and 2 (b + c)",
+		);
+	}
+}
@@ -0,0 +1,177 @@
+/*!
+# Oxford Join: Consuming Joins.
+*/
+
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+use crate::Conjunction;
+
+
+
+/// # Into Oxford Join.
+///
+/// This trait provides a _consuming_ alternative to
+/// [`OxfordJoin::oxford_join`](crate::OxfordJoin::oxford_join) for owned
+/// collections.
+///
+/// Because the collection is consumed, its first item can be converted —
+/// rather than copied — into the output. For `String`s, that means its
+/// buffer gets reused, saving an allocation if it has enough spare capacity
+/// to hold the result (or can be grown in place).
+///
+/// Unlike the borrowing version, the output is always an owned `String`, so
+/// the collection can be dropped afterward without lifetime fuss. (Single-item
+/// `String` sets are moved as-is, no copying required.)
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, IntoOxfordJoin};
+///
+/// let set = vec![
+///     String::from("Apples"),
+///     String::from("Oranges"),
+///     String::from("Bananas"),
+/// ];
+/// assert_eq!(
+///     set.into_oxford_join(Conjunction::And),
+///     "Apples, Oranges, and Bananas",
+/// );
+/// ```
+pub trait IntoOxfordJoin {
+	/// # Into Oxford Join.
+	///
+	/// Consume the set, joining it with Oxford Commas as necessary.
+	fn into_oxford_join(self, glue: Conjunction) -> String;
+}
+
+impl<T> IntoOxfordJoin for Vec<T> where T: AsRef<str> + Into<String> {
+	#[inline]
+	fn into_oxford_join(self, glue: Conjunction) -> String {
+		let (glue, len) = measure(&self, glue);
+		join(self, glue, len)
+	}
+}
+
+impl<T, const N: usize> IntoOxfordJoin for [T; N] where T: AsRef<str> + Into<String> {
+	#[inline]
+	fn into_oxford_join(self, glue: Conjunction) -> String {
+		let (glue, len) = measure(&self, glue);
+		join(self, glue, len)
+	}
+}
+
+
+
+/// # Measure.
+///
+/// Resolve the conjunction and calculate the total output length for the
+/// set.
+fn measure<'a, T: AsRef<str>>(set: &[T], glue: Conjunction<'a>)
+-> (Conjunction<'a>, usize) {
+	let glue = set.last().map_or(glue, |last| glue.for_last(last.as_ref()));
+	let len = set.iter()
+		.map(|s| s.as_ref().len())
+		.fold(glue.glue_len(set.len()), usize::saturating_add);
+	(glue, len)
+}
+
+/// # Join.
+///
+/// Join the (measured) set, converting its first item into the output buffer.
+/// With only one item, that is all there is to it; strings are simply moved.
+fn join<I>(set: I, glue: Conjunction, len: usize) -> String
+where
+	I: IntoIterator,
+	I::IntoIter: ExactSizeIterator,
+	I::Item: AsRef<str> + Into<String> {
+	// Start with the first.
+	let mut iter = set.into_iter();
+	let count = iter.len();
+	let Some(first) = iter.next() else { return String::new(); };
+	let mut out: String = first.into();
+	out.reserve(len - out.len());
+
+	// Write the rest.
+	let mut idx = 1;
+	for s in iter {
+		idx += 1;
+		if idx == count {
+			let parts = if count == 2 { glue.pair_parts() } else { glue.list_parts(", ") };
+			for p in parts { out.push_str(p); }
+		}
+		else { out.push_str(", "); }
+		out.push_str(s.as_ref());
+	}
+
+	out
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::cross_check;
+	use alloc::{
+		borrow::ToOwned,
+		vec,
+	};
+
+	#[test]
+	fn t_into_oxford_join() {
+		// Owned.
+		cross_check(|set, c| {
+			let owned: Vec<String> = set.iter().map(|&s| s.to_owned()).collect();
+			owned.into_oxford_join(c)
+		});
+
+		// Borrowed.
+		cross_check(|set, c| set.to_vec().into_oxford_join(c));
+	}
+
+	#[test]
+	fn t_into_oxford_join_reuse() {
+		// Give the first entry plenty of room.
+		let mut first = String::with_capacity(64);
+		first.push_str("Apples");
+		let ptr = first.as_ptr();
+
+		let set = vec![first, "Bananas".to_owned(), "Carrots".to_owned()];
+		let out = set.into_oxford_join(Conjunction::And);
+		assert_eq!(out, "Apples, Bananas, and Carrots");
+		assert_eq!(out.as_ptr(), ptr, "The buffer was not reused.");
+	}
+
+	#[test]
+	fn t_into_oxford_join_single() {
+		// Vec.
+		let first = "Apples".to_owned();
+		let ptr = first.as_ptr();
+		let out = vec![first].into_oxford_join(Conjunction::And);
+		assert_eq!(out, "Apples");
+		assert_eq!(out.as_ptr(), ptr, "The string was copied.");
+
+		// Array.
+		let first = "Apples".to_owned();
+		let ptr = first.as_ptr();
+		let out = [first].into_oxford_join(Conjunction::And);
+		assert_eq!(out, "Apples");
+		assert_eq!(out.as_ptr(), ptr, "The string was copied.");
+	}
+
+	#[test]
+	fn t_into_oxford_join_array() {
+		assert_eq!(<[String; 0]>::default().into_oxford_join(Conjunction::And), "");
+		assert_eq!(
+			["Apples".to_owned(), "Bananas".to_owned()].into_oxford_join(Conjunction::Or),
+			"Apples or Bananas",
+		);
+		assert_eq!(
+			["Apples", "Bananas", "Carrots"].into_oxford_join(Conjunction::Nor),
+			"Apples, Bananas, nor Carrots",
+		);
+	}
+}
@@ -0,0 +1,252 @@
+/*!
+# Oxford Join: I/O.
+*/
+
+use crate::{
+	Conjunction,
+	OxfordJoin,
+};
+use core::fmt;
+use std::{
+	io,
+	string::String,
+};
+
+
+
+/// # Oxford Write (I/O).
+///
+/// Write the Oxford-joined set directly to an [`io::Write`](std::io::Write)
+/// stream — stdout, a file, etc. — without building a `String` first.
+///
+/// The output is exactly the same as [`OxfordJoin::oxford_join`]'s.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, oxford_write_io};
+///
+/// let mut out = Vec::new();
+/// oxford_write_io(&mut out, &["Apples", "Oranges", "Bananas"], Conjunction::And)
+///     .unwrap();
+/// assert_eq!(out, b"Apples, Oranges, and Bananas");
+/// ```
+///
+/// ## Errors
+///
+/// Any errors encountered while writing are passed through as-is, ending the
+/// join early.
+pub fn oxford_write_io<W, S>(w: &mut W, set: &S, glue: Conjunction)
+-> io::Result<()>
+where W: io::Write + ?Sized, S: OxfordJoin + ?Sized {
+	let mut adapter = Adapter { inner: w, error: None };
+	match set.oxford_join_progress(glue, &mut adapter, &mut |_| {}) {
+		Ok(()) => Ok(()),
+		// Recover the original error, if there was one.
+		Err(fmt::Error) => Err(
+			adapter.error.unwrap_or_else(|| io::Error::other("formatting error"))
+		),
+	}
+}
+
+
+
+/// # Join Writer.
+///
+/// This streams an Oxford-joined list to an [`io::Write`](std::io::Write)
+/// sink — a file, socket, etc. — one item at a time, making it possible to
+/// join huge lists without materializing them in memory.
+///
+/// Separators are written lazily as items are [pushed](JoinWriter::push),
+/// but because the conjunction (and the pair/list distinction) depends on
+/// which item comes _last_, one item is always held back until the next push
+/// or the final call to [`JoinWriter::finish`].
+///
+/// The output is exactly the same as [`OxfordJoin::oxford_join`]'s.
+///
+/// Note that `finish` must be called to write the last item; if the writer is
+/// simply dropped, it will be lost.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, JoinWriter};
+///
+/// let mut out = Vec::new();
+/// let mut writer = JoinWriter::new(&mut out, Conjunction::And);
+/// for fruit in ["Apples", "Oranges", "Bananas"] {
+///     writer.push(fruit).unwrap();
+/// }
+/// writer.finish().unwrap();
+/// assert_eq!(out, b"Apples, Oranges, and Bananas");
+/// ```
+pub struct JoinWriter<'a, W: io::Write + ?Sized> {
+	/// # Writer.
+	inner: &'a mut W,
+
+	/// # Conjunction.
+	glue: Conjunction<'a>,
+
+	/// # Pending (Last) Item.
+	last: String,
+
+	/// # Items Pushed.
+	count: usize,
+}
+
+impl<W: io::Write + ?Sized> fmt::Debug for JoinWriter<'_, W> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("JoinWriter")
+			.field("glue", &self.glue)
+			.field("last", &self.last)
+			.field("count", &self.count)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a, W: io::Write + ?Sized> JoinWriter<'a, W> {
+	#[must_use]
+	/// # New.
+	///
+	/// Create a new writer for the sink and conjunction.
+	pub const fn new(inner: &'a mut W, glue: Conjunction<'a>) -> Self {
+		Self { inner, glue, last: String::new(), count: 0 }
+	}
+
+	#[must_use]
+	/// # Count.
+	///
+	/// Return the number of items pushed so far.
+	pub const fn count(&self) -> usize { self.count }
+
+	/// # Push.
+	///
+	/// Add an item to the list.
+	///
+	/// The first item is written straight away, but subsequent ones are
+	/// held until the next push (or finish), at which point the previous
+	/// item is written along with its separator.
+	///
+	/// ## Errors
+	///
+	/// Any errors encountered while writing are passed through as-is.
+	pub fn push(&mut self, item: &str) -> io::Result<()> {
+		match self.count {
+			0 => { self.inner.write_all(item.as_bytes())?; },
+			1 => { self.last.push_str(item); },
+			_ => {
+				self.inner.write_all(b", ")?;
+				self.inner.write_all(self.last.as_bytes())?;
+				self.last.clear();
+				self.last.push_str(item);
+			},
+		}
+		self.count += 1;
+		Ok(())
+	}
+
+	/// # Finish.
+	///
+	/// Write the conjunction and last item, if any, and flush the sink.
+	///
+	/// ## Errors
+	///
+	/// Any errors encountered while writing or flushing are passed through
+	/// as-is.
+	pub fn finish(self) -> io::Result<()> {
+		let glue = self.glue.for_last(&self.last);
+		let parts = match self.count {
+			0 | 1 => None,
+			2 => Some(glue.pair_parts()),
+			_ => Some(glue.list_parts(", ")),
+		};
+		if let Some(parts) = parts {
+			for p in parts { self.inner.write_all(p.as_bytes())?; }
+			self.inner.write_all(self.last.as_bytes())?;
+		}
+		self.inner.flush()
+	}
+}
+
+
+
+/// # I/O Adapter.
+///
+/// This bridges [`fmt::Write`] and [`io::Write`], holding onto the first I/O
+/// error so it can be returned in place of the less useful [`fmt::Error`].
+struct Adapter<'a, W: io::Write + ?Sized> {
+	/// # Writer.
+	inner: &'a mut W,
+
+	/// # Error.
+	error: Option<io::Error>,
+}
+
+impl<W: io::Write + ?Sized> fmt::Write for Adapter<'_, W> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.inner.write_all(s.as_bytes()).map_err(|e| {
+			self.error = Some(e);
+			fmt::Error
+		})
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::{
+		cross_check,
+		FRUIT,
+	};
+	use std::{
+		string::String,
+		vec::Vec,
+	};
+
+	#[test]
+	fn t_oxford_write_io() {
+		cross_check(|set, c| {
+			let mut out = Vec::new();
+			assert!(oxford_write_io(&mut out, set, c).is_ok());
+			String::from_utf8(out).unwrap()
+		});
+
+		// Errors should pass through.
+		let mut buf = [0_u8; 10];
+		let mut out = buf.as_mut_slice();
+		let err = oxford_write_io(&mut out, &FRUIT, Conjunction::And).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+		assert_eq!(&buf, b"Apples, Ba");
+	}
+
+	#[test]
+	fn t_join_writer() {
+		cross_check(|set, c| {
+			let mut out = Vec::new();
+			let mut writer = JoinWriter::new(&mut out, c);
+			for s in set { assert!(writer.push(s).is_ok()); }
+			assert_eq!(writer.count(), set.len());
+			assert!(writer.finish().is_ok());
+			String::from_utf8(out).unwrap()
+		});
+
+		// Only one item should be held back.
+		let mut out = Vec::new();
+		let mut writer = JoinWriter::new(&mut out, Conjunction::And);
+		for s in FRUIT { assert!(writer.push(s).is_ok()); }
+		drop(writer);
+		assert_eq!(out, b"Apples, Bananas, Carrots, Dates");
+
+		// Errors should pass through.
+		let mut buf = [0_u8; 10];
+		let mut out = buf.as_mut_slice();
+		let mut writer = JoinWriter::new(&mut out, Conjunction::And);
+		assert!(writer.push("Apples").is_ok());
+		assert!(writer.push("Bananas").is_ok());
+		let err = writer.push("Carrots").unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+		assert_eq!(&buf, b"Apples, Ba");
+	}
+}
@@ -0,0 +1,103 @@
+/*!
+# Oxford Join: Map Keys.
+*/
+
+use alloc::{
+	borrow::Cow,
+	collections::BTreeMap,
+};
+use crate::{
+	Conjunction,
+	JoinStyle,
+	OxfordJoin,
+};
+
+
+
+/// # Oxford Join Keys.
+///
+/// [`OxfordJoin`] is implemented for `BTreeMap`s, but joins their _values_.
+/// This extension trait joins the (sorted) _keys_ instead, handy for
+/// listing things like configuration option names.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordJoin, OxfordJoinKeys};
+/// use std::collections::BTreeMap;
+///
+/// let map = BTreeMap::from([("verbose", "-v"), ("help", "-h"), ("quiet", "-q")]);
+/// assert_eq!(
+///     map.oxford_join_keys(Conjunction::And),
+///     "help, quiet, and verbose",
+/// );
+///
+/// // Versus the values.
+/// assert_eq!(map.oxford_join(Conjunction::And), "-h, -q, and -v");
+/// ```
+pub trait OxfordJoinKeys {
+	/// # Oxford Join (Keys).
+	///
+	/// Join the keys with Oxford Commas as necessary.
+	fn oxford_join_keys(&self, glue: Conjunction) -> Cow<'_, str>;
+}
+
+impl<K: AsRef<str>, V> OxfordJoinKeys for BTreeMap<K, V> {
+	fn oxford_join_keys(&self, glue: Conjunction) -> Cow<'_, str> {
+		match self.len() {
+			0 => Cow::Borrowed(""),
+			1 => Cow::Borrowed(self.keys().next().map_or("", K::as_ref)),
+			// The output is always owned here; the wrapper just needs to
+			// outlive the join.
+			_ => Cow::Owned(Keys(self).oxford_join(glue).into_owned()),
+		}
+	}
+}
+
+
+
+/// # Keys Wrapper.
+///
+/// This wraps a map so its keys can be fed to the usual join machinery.
+struct Keys<'a, K, V>(&'a BTreeMap<K, V>);
+
+impl<K: AsRef<str>, V> OxfordJoin for Keys<'_, K, V> {
+	#[inline]
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+		crate::join_styled(self, JoinStyle::new(glue))
+	}
+
+	#[inline]
+	fn oxford_count(&self) -> usize { self.0.len() }
+
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+		for k in self.0.keys() { cb(k.as_ref()); }
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::cross_check;
+
+	#[test]
+	fn t_oxford_join_keys() {
+		cross_check(|set, c| {
+			// Insert in reverse to make sure the output is sorted.
+			let map: BTreeMap<&str, i32> = set.iter()
+				.rev()
+				.zip(0..)
+				.map(|(k, v)| (*k, v))
+				.collect();
+			let out = map.oxford_join_keys(c).into_owned();
+			assert_eq!(out.len(), out.capacity());
+			out
+		});
+
+		// Single keys should be borrowed.
+		let map = BTreeMap::from([("Apples", 1)]);
+		assert!(matches!(map.oxford_join_keys(Conjunction::And), Cow::Borrowed("Apples")));
+	}
+}
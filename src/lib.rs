@@ -22,7 +22,22 @@ The return formatting depends on the size of the set:
 n: "first, second, …, <CONJUNCTION> last"
 ```
 
-This crate is `#![no_std]`-compatible.
+This crate is `#![no_std]`-compatible. Its `String`-based functionality —
+the [`OxfordJoin`] trait, etc. — requires `alloc`, but that can be disabled for
+strictly heap-free environments; the [`Display`](core::fmt::Display)-based
+wrappers, [`Conjunction`], and [`oxford_join_slice`] — which joins into a
+caller-provided byte buffer — work either way.
+
+## Optional Features
+
+| Feature | Default | Description |
+| ------- | ------- | ----------- |
+| `alloc` | Y | Enable the [`OxfordJoin`] trait and other `String`-based joins. |
+| `html` | | Enable HTML-escaped joins via [`OxfordJoin::oxford_join_html`]. |
+| `i18n` | | Enable language-based conjunction lookups via [`Conjunction::for_locale`]. |
+| `small` | | Enable stack-allocated joins via [`OxfordJoin::oxford_join_small`]. |
+| `std` | | Enable direct-to-[`io::Write`](std::io::Write) joins via [`oxford_write_io`] and [`JoinWriter`], and result caching via [`OxfordCache`]. |
+| `unicode-width` | | Enable display-width-aware clamping via [`OxfordJoin::oxford_join_clamped_width`]. |
 
 ## Examples
 
@@ -31,6 +46,7 @@ slice-y things holding `AsRef<str>` will inherit the [`OxfordJoin::oxford_join`]
 method for joining.
 
 ```
+# #[cfg(feature = "alloc")] {
 use oxford_join::{Conjunction, OxfordJoin};
 
 let set = ["Apples", "Oranges"];
@@ -45,6 +61,7 @@ assert_eq!(set.oxford_and(), "Apples, Oranges, and Bananas");
 assert_eq!(set.oxford_and_or(), "Apples, Oranges, and/or Bananas");
 assert_eq!(set.oxford_nor(), "Apples, Oranges, nor Bananas");
 assert_eq!(set.oxford_or(), "Apples, Oranges, or Bananas");
+# }
 ```
 
 There is also a [`Display`](core::fmt::Display)-based [`OxfordJoinFmt`] wrapper
@@ -114,39 +131,166 @@ That's all, folks!
 #![allow(clippy::module_name_repetitions, reason = "Repetition is preferred.")]
 
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+mod macros;
+#[cfg(feature = "alloc")]
+mod borrowed;
+#[cfg(feature = "alloc")]
+mod by;
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "alloc")]
+mod chars;
+#[cfg(feature = "alloc")]
+mod collect;
+mod error;
 mod fmt;
+#[cfg(feature = "alloc")]
+mod group;
+#[cfg(feature = "alloc")]
+mod into;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "alloc")]
+mod keys;
+#[cfg(feature = "alloc")]
+mod owned;
+#[cfg(feature = "alloc")]
+mod resolved;
+#[cfg(feature = "alloc")]
+mod rev;
+#[cfg(feature = "small")]
+mod small;
+mod slice;
+#[cfg(feature = "alloc")]
+mod some;
+mod style;
+mod trunc;
 
 // Re-export.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use borrowed::OxfordJoinBorrowed;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use by::OxfordJoinBy;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use cache::OxfordCache;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use chars::OxfordJoinChars;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use collect::CollectOxford;
+pub use error::InlineError;
 pub use fmt::{
 	JoinFmt,
 	OxfordJoinFmt,
+	OxfordJoinStrFmt,
+	OxfordPair,
+};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use group::oxford_join_grouped;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use into::IntoOxfordJoin;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use io::{
+	JoinWriter,
+	oxford_write_io,
 };
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use keys::OxfordJoinKeys;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use owned::OwnedConjunction;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use resolved::ResolvedConjunction;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use rev::OxfordJoinRev;
+#[cfg(feature = "small")]
+#[cfg_attr(docsrs, doc(cfg(feature = "small")))]
+pub use small::SmallJoin;
+pub use slice::{
+	CapacityError,
+	oxford_join_slice,
+};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use some::OxfordJoinSome;
+pub use style::JoinStyle;
+pub use trunc::TruncStyle;
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+/// # Macro Support.
+///
+/// This re-exports the types referenced by [`impl_oxford_join!`], so
+/// downstream crates don't need to import them themselves.
+pub mod __private {
+	pub use alloc::borrow::Cow;
+}
 
+#[cfg(feature = "alloc")]
 use alloc::{
 	borrow::Cow,
+	boxed::Box,
 	collections::{
+		BinaryHeap,
 		BTreeSet,
 		BTreeMap,
+		LinkedList,
+		VecDeque,
 	},
 	string::String,
 	vec::Vec,
 };
 use core::{
 	borrow::Borrow,
+	cmp::Ordering,
 	ops::Deref,
 };
+#[cfg(feature = "alloc")]
+use core::{
+	fmt::Display,
+	ops::{
+		Range,
+		Sub,
+	},
+};
 
 
 
+#[cfg(feature = "alloc")]
 /// # Comma + Space.
 const COMMASPACE: &[u8] = b", ";
 
+#[cfg(feature = "alloc")]
+/// # One-Pass Threshold.
+///
+/// Slices with at least this many middle items are joined in a single pass,
+/// using an estimated rather than exact capacity. (See the `oj_join`
+/// benchmarks.)
+const ONE_PASS: usize = 254;
+
+
 
 
-#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq)]
+#[derive(Debug, Copy, Clone, Default)]
+#[non_exhaustive]
 /// # Conjunction.
 ///
 /// This is the glue used to bind the last entry in an [`oxford_join`](OxfordJoin::oxford_join)ed set.
@@ -169,8 +313,47 @@ const COMMASPACE: &[u8] = b", ";
 /// ```
 ///
 /// If the set is empty or singular, there's nothing to conjunct.
+///
+/// ## Equality and Ordering.
+///
+/// Equality (and hashing) is _structural_: [`Conjunction::And`] and
+/// `Conjunction::Other("and")` render the same way, but are not considered
+/// equal. [`Conjunction::Dynamic`] values are compared by function address.
+/// (Use [`Conjunction::semantic_eq`] to compare by output instead.)
+///
+/// Ordering is _semantic_, sorting conjunctions alphabetically by their
+/// [string representation](Conjunction::as_str). To remain consistent with
+/// equality, ties — like the above — are broken by variant, with presets
+/// coming first.
+///
+/// ```
+/// use oxford_join::Conjunction;
+///
+/// let mut list = vec![
+///     Conjunction::Or,
+///     Conjunction::Other("and"),
+///     Conjunction::Ampersand,
+///     Conjunction::And,
+/// ];
+/// list.sort();
+/// assert_eq!(
+///     list,
+///     [
+///         Conjunction::Ampersand,
+///         Conjunction::And,
+///         Conjunction::Other("and"),
+///         Conjunction::Or,
+///     ],
+/// );
+/// ```
 pub enum Conjunction<'a> {
 	/// # Ampersand (&).
+	///
+	/// Note that the ampersand is a special character in HTML, and must be
+	/// escaped (`&amp;`) when joined output is destined for a web page. The
+	/// optional `html` crate feature provides an
+	/// [`oxford_join_html`](OxfordJoin::oxford_join_html) method that takes
+	/// care of it.
 	Ampersand,
 
 	#[default]
@@ -180,7 +363,32 @@ pub enum Conjunction<'a> {
 	/// # And/Or.
 	AndOr,
 
+	/// # Comma.
+	///
+	/// A degenerate conjunction that binds _every_ item — including the last
+	/// of a pair — with a plain comma, no words attached: `"a, b"`,
+	/// `"a, b, c"`, etc.
+	///
+	/// This is mainly useful for terse, machine-readable-ish output where the
+	/// caller still wants a single code path for all conjunction styles.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # #[cfg(feature = "alloc")] {
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// assert_eq!(["a", "b"].oxford_join(Conjunction::Comma), "a, b");
+	/// assert_eq!(["a", "b", "c"].oxford_join(Conjunction::Comma), "a, b, c");
+	/// # }
+	/// ```
+	Comma,
+
 	/// # Nor.
+	///
+	/// Note that two-item sets are rendered as `"first nor last"`; for the
+	/// more grammatical `"neither first nor last"`, use a [`JoinStyle`] or
+	/// [`OxfordJoinFmt`] with correlatives enabled.
 	Nor,
 
 	/// # Or.
@@ -189,6 +397,88 @@ pub enum Conjunction<'a> {
 	/// # Custom Entry (Trimmed).
 	Other(&'a str),
 
+	/// # Dynamic.
+	///
+	/// Some languages change the conjunction depending on the word that
+	/// follows it, like Spanish's `"y"`, which becomes `"e"` before words
+	/// beginning with an "i" sound: "Juan e Inés".
+	///
+	/// This variant wraps a selector function which receives the _last_ item
+	/// of the set and returns the conjunction to use with it. Because it is a
+	/// plain `fn` pointer, it cannot capture state, and its return value must
+	/// be `'static`.
+	///
+	/// Note that the [`OxfordJoinFmt`] wrapper, which works with [`Display`]
+	/// rather than string types, only passes the first 32 (or so) bytes of
+	/// the last item to the selector; that should be plenty for phonetic
+	/// checks like the above.
+	///
+	/// Outside the context of a join, dynamic conjunctions have no fixed
+	/// representation; [`Conjunction::as_str`] will return an empty string.
+	/// Use [`Conjunction::for_last`] to resolve them.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # #[cfg(feature = "alloc")] {
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// /// # Spanish "And".
+	/// fn y(next: &str) -> &'static str {
+	///     let next = next.to_lowercase();
+	///     let next = next.strip_prefix('h').unwrap_or(&next);
+	///     if next.starts_with(['i', 'í']) && ! next.starts_with(['a', 'e', 'o', 'u']) {
+	///         "e"
+	///     }
+	///     else { "y" }
+	/// }
+	///
+	/// let glue = Conjunction::Dynamic(y);
+	/// assert_eq!(["Juan", "Inés"].oxford_join(glue), "Juan e Inés");
+	/// assert_eq!(["Juan", "María"].oxford_join(glue), "Juan y María");
+	/// assert_eq!(["padre", "madre", "hijo"].oxford_join(glue), "padre, madre, e hijo");
+	/// # }
+	/// ```
+	Dynamic(fn(&str) -> &'static str),
+
+	/// # Custom Entry (Raw).
+	///
+	/// Unlike [`Conjunction::Other`], which is padded with spaces (and
+	/// commas) automatically, this variant gives you full control over the
+	/// glue, useful for punctuation-style conjunctions like arrows and
+	/// dashes.
+	///
+	/// The `pair` value is used as-is to bind two-item sets, while `list` is
+	/// used as-is to bind the last item of larger sets, in place of the usual
+	/// separator-conjunction-space combination. (The separator is still used
+	/// between the other items.)
+	///
+	/// For display purposes, e.g. [`Conjunction::as_str`], the `pair` value
+	/// with any surrounding ASCII whitespace trimmed is used.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # #[cfg(feature = "alloc")] {
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let glue = Conjunction::OtherRaw { pair: " → ", list: " → " };
+	/// assert_eq!(["A", "B"].oxford_join(glue), "A → B");
+	/// assert_eq!(["A", "B", "C"].oxford_join(glue), "A, B → C");
+	///
+	/// let glue = Conjunction::OtherRaw { pair: "–", list: ",–" };
+	/// assert_eq!(["1999", "2001"].oxford_join(glue), "1999–2001");
+	/// assert_eq!(glue.as_str(), "–");
+	/// # }
+	/// ```
+	OtherRaw {
+		/// # Two-Item Glue.
+		pair: &'a str,
+
+		/// # Three+ Final Glue.
+		list: &'a str,
+	},
+
 	/// # Plus (+).
 	Plus,
 }
@@ -216,12 +506,178 @@ impl core::fmt::Display for Conjunction<'_> {
 	}
 }
 
+impl Eq for Conjunction<'_> {}
+
 impl<'a> From<&'a str> for Conjunction<'a> {
 	#[inline]
 	fn from(src: &'a str) -> Self { Self::Other(src.trim()) }
 }
 
+impl core::hash::Hash for Conjunction<'_> {
+	#[inline]
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		core::mem::discriminant(self).hash(state);
+		match self {
+			Self::Other(s) => s.hash(state),
+			Self::Dynamic(f) => (*f as *const ()).hash(state),
+			Self::OtherRaw { pair, list } => {
+				pair.hash(state);
+				list.hash(state);
+			},
+			_ => {},
+		}
+	}
+}
+
+impl Ord for Conjunction<'_> {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_str().cmp(other.as_str())
+			.then_with(|| self.rank().cmp(&other.rank()))
+			.then_with(|| match (self, other) {
+				// Dynamic functions can only be compared by address.
+				(Self::Dynamic(a), Self::Dynamic(b)) =>
+					(*a as *const ()).cmp(&(*b as *const ())),
+				// Raw values have more to compare.
+				(Self::OtherRaw { pair: a1, list: a2 }, Self::OtherRaw { pair: b1, list: b2 }) =>
+					(a1, a2).cmp(&(b1, b2)),
+				_ => Ordering::Equal,
+			})
+	}
+}
+
+impl PartialEq for Conjunction<'_> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::Other(a), Self::Other(b)) => a == b,
+			// Dynamic functions can only be compared by address.
+			(Self::Dynamic(a), Self::Dynamic(b)) => core::ptr::eq(*a as *const (), *b as *const ()),
+			(Self::OtherRaw { pair: a1, list: a2 }, Self::OtherRaw { pair: b1, list: b2 }) =>
+				a1 == b1 && a2 == b2,
+			_ => self.rank() == other.rank(),
+		}
+	}
+}
+
+impl PartialOrd for Conjunction<'_> {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl<'a> Conjunction<'a> {
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+	#[must_use]
+	/// # Resolve.
+	///
+	/// Pre-build the conjunction's glue strings — `" and "`, `", and "`,
+	/// etc. — returning a [`ResolvedConjunction`] handle that can be reused
+	/// to join any number of sets without redoing that work each time.
+	///
+	/// This is a micro-optimization for batch joins sharing the same
+	/// (custom) conjunction.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// let glue = Conjunction::Other("plus").resolve();
+	/// let sets = [
+	///     vec!["1", "2"],
+	///     vec!["3", "4", "5"],
+	/// ];
+	/// let out: Vec<_> = sets.iter().map(|s| glue.oxford_join(s)).collect();
+	/// assert_eq!(out, ["1 plus 2", "3, 4, plus 5"]);
+	/// ```
+	pub fn resolve(self) -> ResolvedConjunction<'a> { ResolvedConjunction::new(self) }
+
+	/// # Try Inline.
+	///
+	/// Create a [`Conjunction::Other`] from a string, same as `From<&str>`,
+	/// but only if it contains no line feeds, carriage returns, or tabs,
+	/// any of which could break single-line output.
+	///
+	/// As with `From<&str>`, leading and trailing spaces are trimmed.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// assert_eq!(Conjunction::try_inline(" und "), Ok(Conjunction::Other("und")));
+	///
+	/// let err = Conjunction::try_inline("and\nthen").unwrap_err();
+	/// assert_eq!(err.char(), '\n');
+	/// assert_eq!(err.index(), 3);
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// If the string contains `\n`, `\r`, or `\t`, an [`InlineError`] is
+	/// returned describing the first occurrence.
+	pub const fn try_inline(src: &'a str) -> Result<Self, InlineError> {
+		let bytes = src.as_bytes();
+		let mut idx = 0;
+		while idx < bytes.len() {
+			match bytes[idx] {
+				b'\n' => return Err(InlineError::new('\n', idx)),
+				b'\r' => return Err(InlineError::new('\r', idx)),
+				b'\t' => return Err(InlineError::new('\t', idx)),
+				_ => {},
+			}
+			idx += 1;
+		}
+		Ok(Self::Other(src.trim_ascii()))
+	}
+}
+
 impl Conjunction<'_> {
+	/// # Default.
+	///
+	/// The default conjunction, [`Conjunction::And`], as an associated
+	/// constant, handy for compile-time defaults in generic code where
+	/// [`Default::default`] can't be called.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// const GLUE: Conjunction = Conjunction::DEFAULT;
+	/// assert_eq!(GLUE, Conjunction::And);
+	/// assert_eq!(GLUE, Conjunction::default());
+	/// ```
+	pub const DEFAULT: Conjunction<'static> = Conjunction::And;
+
+	/// # All Presets.
+	///
+	/// This array holds each of the preset conjunctions — everything but
+	/// [`Conjunction::Other`], [`Conjunction::Dynamic`], and
+	/// [`Conjunction::OtherRaw`] — in alphabetical order, handy for building
+	/// settings menus and the like.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// let names: Vec<&str> = Conjunction::ALL.iter()
+	///     .filter_map(Conjunction::preset_name)
+	///     .collect();
+	/// assert_eq!(names, ["ampersand", "and", "and_or", "comma", "nor", "or", "plus"]);
+	/// ```
+	pub const ALL: [Conjunction<'static>; 7] = [
+		Conjunction::Ampersand,
+		Conjunction::And,
+		Conjunction::AndOr,
+		Conjunction::Comma,
+		Conjunction::Nor,
+		Conjunction::Or,
+		Conjunction::Plus,
+	];
+
 	#[must_use]
 	/// # As Str.
 	///
@@ -231,545 +687,4692 @@ impl Conjunction<'_> {
 			Self::Ampersand => "&",
 			Self::And => "and",
 			Self::AndOr => "and/or",
+			Self::Comma => ",",
 			Self::Nor => "nor",
 			Self::Or => "or",
 			Self::Other(s) => s,
 			Self::Plus => "+",
+			Self::Dynamic(_) => "",
+			Self::OtherRaw { pair, .. } => pair.trim_ascii(),
 		}
 	}
 
 	#[must_use]
 	/// # Length.
 	///
-	/// Return the string length of the conjunction.
+	/// Return the string length of the conjunction, in bytes.
+	///
+	/// For the presets, bytes and characters are one and the same, but
+	/// custom [`Conjunction::Other`] values might contain multi-byte
+	/// characters; if you need a character count for e.g. alignment, use
+	/// [`Conjunction::char_len`] instead.
 	pub const fn len(&self) -> usize {
 		match self {
 			Self::And | Self::Nor => 3,
 			Self::Or => 2,
-			Self::Ampersand | Self::Plus => 1,
+			Self::Ampersand | Self::Comma | Self::Plus => 1,
 			Self::AndOr => 6,
 			Self::Other(s) => s.len(),
+			Self::Dynamic(_) => 0,
+			Self::OtherRaw { pair, .. } => pair.trim_ascii().len(),
 		}
 	}
 
 	#[must_use]
-	/// # Is Empty.
+	/// # Pair Glue Parts.
 	///
-	/// An empty conjunction makes no sense, but because `Conjunction::Other`
-	/// wraps arbitrary values, it is worth checking.
-	pub const fn is_empty(&self) -> bool {
+	/// Return the leading whitespace, conjunction, and trailing whitespace
+	/// used to bind two-item sets, e.g. `[" ", "and", " "]`.
+	pub(crate) const fn pair_parts(&self) -> [&str; 3] {
 		match self {
-			Self::Other(s) => s.is_empty(),
-			_ => false,
+			Self::Comma => [",", "", " "],
+			Self::OtherRaw { pair, .. } => ["", pair, ""],
+			_ => [" ", self.as_str(), " "],
 		}
 	}
-}
 
-impl Conjunction<'_> {
-	/// # Oxford Join (Generic).
+	#[must_use]
+	/// # Pair Glue Length.
 	///
-	/// This convenience method allows you to Oxford-join _any_ iterable data
-	/// source that yields `AsRef<str>`.
+	/// Return the number of bytes the conjunction — and its surrounding
+	/// whitespace — contributes to a two-item join, e.g. `" and "`.
 	///
-	/// For types that implement [`OxfordJoin`] directly, the trait methods
-	/// should be preferred as they're specialized, but you'll get the same
-	/// answer either way.
+	/// Note that [`Conjunction::Dynamic`] values should be
+	/// [resolved](Conjunction::for_last) first.
 	///
 	/// ## Examples
 	///
 	/// ```
+	/// # #[cfg(feature = "alloc")] {
 	/// use oxford_join::{Conjunction, OxfordJoin};
-	/// const LIST: [&str; 3] = ["Apples", "Bananas", "Carrots"];
 	///
-	/// // A contrived example to spell it out…
+	/// let glue = Conjunction::And;
+	/// assert_eq!(glue.pair_glue_len(), " and ".len());
+	///
+	/// let set = ["Apples", "Oranges"];
 	/// assert_eq!(
-	///     Conjunction::And.oxford_join("hello".chars().map(String::from)),
-	///     "h, e, l, l, and o"
+	///     set.oxford_join(glue).len(),
+	///     set[0].len() + set[1].len() + glue.pair_glue_len(),
 	/// );
+	/// # }
 	/// ```
-	pub fn oxford_join<I, T>(&self, iter: I) -> String
-	where T: AsRef<str>, I: IntoIterator<Item=T> {
-		// Pull the first value, ensuring there actually is one.
-		let mut iter = iter.into_iter();
-		let Some(next) = iter.next() else { return String::new(); };
-
-		// MAGIC NUMBER: one fuzzy preallocation improves collection times a
-		// lot compared to separate item-by-item reserves.
-		let mut out = String::with_capacity(64);
-		out.push_str(next.as_ref());
-
-		// We have a second item!
-		if let Some(mut buf) = iter.next() {
-			// Can we get an Nth?!
-			let mut many = false;
-			for next in iter.map(|n| core::mem::replace(&mut buf, n)) {
-				// Add the _previous_ value to the output. (The "current" value
-				// is now in the buffer.)
-				out.push_str(", ");
-				out.push_str(next.as_ref());
-				many = true;
-			}
-
-			// Add the final punctuation and conjunction.
-			if many { out.push_str(", "); } else { out.push(' '); }
-			out.push_str(self.as_str());
-			out.push(' ');
-
-			// Cap it off with the last item.
-			out.push_str(buf.as_ref());
-		}
-
-		out
-	}
-}
-
-impl Conjunction<'_> {
-	/// # Append for Three+.
-	///
-	/// This writes the conjunction with a leading comma-space and trailing
-	/// space to the buffer, e.g. `", and "`.
-	fn append_to(&self, v: &mut Vec<u8>) {
-		match self {
-			Self::Ampersand => { v.extend_from_slice(b", & "); },
-			Self::And => { v.extend_from_slice(b", and "); },
-			Self::AndOr => { v.extend_from_slice(b", and/or "); },
-			Self::Nor => { v.extend_from_slice(b", nor "); },
-			Self::Or => { v.extend_from_slice(b", or "); },
-			Self::Other(s) => {
-				v.extend_from_slice(COMMASPACE);
-				v.extend_from_slice(s.as_bytes());
-				v.push(b' ');
-			},
-			Self::Plus => { v.extend_from_slice(b", + "); },
-		}
+	pub const fn pair_glue_len(&self) -> usize {
+		let [a, b, c] = self.pair_parts();
+		a.len() + b.len() + c.len()
 	}
 
-	/// # Append for Two.
+	#[must_use]
+	/// # List Glue Parts.
 	///
-	/// This writes the conjunction with a leading and trailing space to the
-	/// buffer, e.g. `" and "`.
-	fn append_two(&self, v: &mut Vec<u8>) {
+	/// Return the separator, conjunction, and trailing whitespace used to
+	/// bind the last item of a three-plus set, e.g. `[", ", "and", " "]`.
+	pub(crate) const fn list_parts<'s>(&'s self, sep: &'s str) -> [&'s str; 3] {
 		match self {
-			Self::Ampersand => { v.extend_from_slice(b" & "); },
-			Self::And => { v.extend_from_slice(b" and "); },
-			Self::AndOr => { v.extend_from_slice(b" and/or "); },
-			Self::Nor => { v.extend_from_slice(b" nor "); },
-			Self::Or => { v.extend_from_slice(b" or "); },
-			Self::Other(s) => {
-				v.push(b' ');
-				v.extend_from_slice(s.as_bytes());
-				v.push(b' ');
-			},
-			Self::Plus => { v.extend_from_slice(b" + "); },
+			Self::Comma => [sep, "", ""],
+			Self::OtherRaw { list, .. } => ["", list, ""],
+			_ => [sep, self.as_str(), " "],
 		}
 	}
-}
-
-
 
-/// # Oxford Join.
-///
-/// Join a slice of strings with Oxford Commas inserted as necessary.
-///
-/// The return formatting depends on the size of the set:
-///
-/// ```text
-/// "" // Zero.
-/// "first" // One.
-/// "first <CONJUNCTION> last" // Two.
-/// "first, second, …, <CONJUNCTION> last" // Three+.
-/// ```
-///
-/// ## Examples
-///
-/// ```
-/// use oxford_join::{Conjunction, OxfordJoin};
-///
-/// let set = ["Apples"];
-/// assert_eq!(set.oxford_join(Conjunction::And), "Apples");
-///
-/// let set = ["Apples", "Oranges"];
-/// assert_eq!(set.oxford_join(Conjunction::Or), "Apples or Oranges");
-///
-/// let set = ["Apples", "Oranges", "Bananas"];
-/// assert_eq!(set.oxford_join(Conjunction::AndOr), "Apples, Oranges, and/or Bananas");
-/// ```
-pub trait OxfordJoin {
-	/// # Oxford Join.
+	#[must_use]
+	/// # List Glue Length.
 	///
-	/// Join a slice of strings with Oxford Commas inserted as necessary.
-	fn oxford_join(&self, glue: Conjunction) -> Cow<str>;
-
-	#[inline]
-	/// # Oxford Join (and).
+	/// Return the number of bytes the final comma, conjunction, and
+	/// whitespace contribute to a three-plus-item join, e.g. `", and "`. (The
+	/// other items are each separated by a two-byte `", "`.)
 	///
-	/// This is equivalent to calling `oxford_join(Conjunction::And)`.
+	/// Note that [`Conjunction::Dynamic`] values should be
+	/// [resolved](Conjunction::for_last) first.
 	///
 	/// ## Examples
 	///
 	/// ```
+	/// # #[cfg(feature = "alloc")] {
 	/// use oxford_join::{Conjunction, OxfordJoin};
 	///
-	/// let set = ["Apples", "Oranges"];
-	/// assert_eq!(set.oxford_join(Conjunction::And), set.oxford_and());
+	/// let glue = Conjunction::And;
+	/// assert_eq!(glue.list_glue_len(), ", and ".len());
+	///
+	/// let set = ["Apples", "Oranges", "Bananas", "Dates"];
+	/// assert_eq!(
+	///     set.oxford_join(glue).len(),
+	///     set.iter().map(|s| s.len()).sum::<usize>() +
+	///     (set.len() - 2) * 2 +
+	///     glue.list_glue_len(),
+	/// );
+	/// # }
 	/// ```
-	fn oxford_and(&self) -> Cow<str> { self.oxford_join(Conjunction::And) }
+	pub const fn list_glue_len(&self) -> usize {
+		let [a, b, c] = self.list_parts(", ");
+		a.len() + b.len() + c.len()
+	}
 
-	#[inline]
-	/// # Oxford Join (and/or).
+	#[must_use]
+	/// # Estimate Joined Length.
 	///
-	/// This is equivalent to calling `oxford_join(Conjunction::AndOr)`.
+	/// Return the length, in bytes, of a join of `count` items, each
+	/// `avg_len` bytes long.
+	///
+	/// If all of the items really are the same length, the result will be
+	/// exact; otherwise it makes for a decent capacity estimate when the
+	/// items aren't all known up front, like when joining from an iterator.
+	/// (This is what [`Conjunction::oxford_join`] does for iterators with
+	/// size hints.)
+	///
+	/// Note that [`Conjunction::Dynamic`] conjunctions have no fixed length,
+	/// so are treated as empty.
 	///
 	/// ## Examples
 	///
 	/// ```
+	/// # #[cfg(feature = "alloc")] {
 	/// use oxford_join::{Conjunction, OxfordJoin};
 	///
-	/// let set = ["Apples", "Oranges"];
-	/// assert_eq!(set.oxford_join(Conjunction::AndOr), set.oxford_and_or());
+	/// let set = ["Apples", "Grapes", "Quince"];
+	/// assert_eq!(
+	///     Conjunction::And.estimate_len(set.len(), 6),
+	///     set.oxford_and().len(),
+	/// );
+	/// # }
 	/// ```
-	fn oxford_and_or(&self) -> Cow<str> { self.oxford_join(Conjunction::AndOr) }
+	pub const fn estimate_len(&self, count: usize, avg_len: usize) -> usize {
+		count.saturating_mul(avg_len).saturating_add(self.glue_len(count))
+	}
 
-	#[inline]
-	/// # Oxford Join (nor).
+	#[must_use]
+	/// # Total Glue Length.
 	///
-	/// This is equivalent to calling `oxford_join(Conjunction::Nor)`.
+	/// Return the combined byte length of all the conjunctions and
+	/// separators needed to join `count` items, saturating rather than
+	/// overflowing for pathologically long custom words.
+	pub(crate) const fn glue_len(&self, count: usize) -> usize {
+		match count {
+			0 | 1 => 0,
+			2 => self.pair_glue_len(),
+			n => self.list_glue_len().saturating_add((n - 2).saturating_mul(2)),
+		}
+	}
+
+	#[must_use]
+	/// # For Last Item.
+	///
+	/// Return the conjunction to use with the given last item.
+	///
+	/// This only matters for [`Conjunction::Dynamic`], which will be resolved
+	/// to an equivalent [`Conjunction::Other`]; all other variants are simply
+	/// returned as-is.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// fn selector(next: &str) -> &'static str {
+	///     if next.starts_with('I') { "e" } else { "y" }
+	/// }
+	///
+	/// let glue = Conjunction::Dynamic(selector);
+	/// assert_eq!(glue.for_last("Inés"), Conjunction::Other("e"));
+	/// assert_eq!(glue.for_last("María"), Conjunction::Other("y"));
+	///
+	/// // Static conjunctions are static.
+	/// assert_eq!(Conjunction::And.for_last("Inés"), Conjunction::And);
+	/// ```
+	pub fn for_last(self, last: &str) -> Self {
+		if let Self::Dynamic(f) = self { Self::Other(f(last)) }
+		else { self }
+	}
+
+	#[must_use]
+	/// # Is Symbol?
+	///
+	/// Returns `true` for the symbolic presets — [`Conjunction::Ampersand`]
+	/// and [`Conjunction::Plus`] — which can be
+	/// [tightened](JoinStyle::with_tight).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// assert!(Conjunction::Ampersand.is_symbol());
+	/// assert!(! Conjunction::And.is_symbol());
+	/// ```
+	pub const fn is_symbol(&self) -> bool {
+		matches!(self, Self::Ampersand | Self::Plus)
+	}
+
+	#[must_use]
+	/// # Correlative.
+	///
+	/// Return the word traditionally paired with the conjunction when
+	/// joining two items, if any, e.g. `"neither"` for [`Conjunction::Nor`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// assert_eq!(Conjunction::And.correlative(), Some("both"));
+	/// assert_eq!(Conjunction::Nor.correlative(), Some("neither"));
+	/// assert_eq!(Conjunction::Or.correlative(), Some("either"));
+	/// assert_eq!(Conjunction::Plus.correlative(), None);
+	/// ```
+	pub const fn correlative(&self) -> Option<&'static str> {
+		match self {
+			Self::And => Some("both"),
+			Self::Nor => Some("neither"),
+			Self::Or => Some("either"),
+			_ => None,
+		}
+	}
+
+	#[must_use]
+	/// # List Correlative.
+	///
+	/// Return the phrase traditionally used to introduce lists of three or
+	/// more items joined by the conjunction, if any, e.g. `"none of"` for
+	/// [`Conjunction::Nor`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// assert_eq!(Conjunction::And.list_correlative(), Some("all of"));
+	/// assert_eq!(Conjunction::Nor.list_correlative(), Some("none of"));
+	/// assert_eq!(Conjunction::Or.list_correlative(), Some("any of"));
+	/// assert_eq!(Conjunction::Plus.list_correlative(), None);
+	/// ```
+	pub const fn list_correlative(&self) -> Option<&'static str> {
+		match self {
+			Self::And => Some("all of"),
+			Self::Nor => Some("none of"),
+			Self::Or => Some("any of"),
+			_ => None,
+		}
+	}
+
+	#[must_use]
+	/// # Preset Name.
+	///
+	/// Return the canonical (lowercase, snake-cased) name of the preset, or
+	/// `None` for the custom variants.
+	///
+	/// Unlike [`Conjunction::as_str`], this is meant to identify the variant
+	/// rather than render it, e.g. `"ampersand"` rather than `"&"`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// assert_eq!(Conjunction::Ampersand.preset_name(), Some("ampersand"));
+	/// assert_eq!(Conjunction::AndOr.preset_name(), Some("and_or"));
+	/// assert_eq!(Conjunction::Other("and").preset_name(), None);
+	///
+	/// // Round trip!
+	/// let name = Conjunction::Nor.preset_name().unwrap();
+	/// assert_eq!(
+	///     Conjunction::ALL.into_iter().find(|c| c.preset_name() == Some(name)),
+	///     Some(Conjunction::Nor),
+	/// );
+	/// ```
+	pub const fn preset_name(&self) -> Option<&'static str> {
+		match self {
+			Self::Ampersand => Some("ampersand"),
+			Self::And => Some("and"),
+			Self::AndOr => Some("and_or"),
+			Self::Comma => Some("comma"),
+			Self::Nor => Some("nor"),
+			Self::Or => Some("or"),
+			Self::Other(_) | Self::Dynamic(_) | Self::OtherRaw { .. } => None,
+			Self::Plus => Some("plus"),
+		}
+	}
+
+	#[must_use]
+	/// # Is Preset?
+	///
+	/// Returns `true` for the preset conjunctions — those in
+	/// [`Conjunction::ALL`] — and `false` for the custom variants.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// const PRESET: bool = Conjunction::Nor.is_preset();
+	/// assert!(PRESET);
+	/// assert!(! Conjunction::Other("nor").is_preset());
+	/// ```
+	pub const fn is_preset(&self) -> bool { self.preset_name().is_some() }
+
+	#[must_use]
+	/// # Character Length.
+	///
+	/// Return the length of the conjunction in `char`s, rather than bytes.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// let glue = Conjunction::Other("και"); // Greek "and".
+	/// assert_eq!(glue.len(), 6);
+	/// assert_eq!(glue.char_len(), 3);
+	/// ```
+	pub const fn char_len(&self) -> usize {
+		let bytes = self.as_str().as_bytes();
+		let mut len = 0;
+		let mut idx = 0;
+		while idx < bytes.len() {
+			// Count everything but continuation bytes.
+			if bytes[idx] & 0b1100_0000 != 0b1000_0000 { len += 1; }
+			idx += 1;
+		}
+		len
+	}
+
+	#[cfg(feature = "unicode-width")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "unicode-width")))]
+	#[must_use]
+	/// # Display Width.
+	///
+	/// Return the number of terminal columns the conjunction occupies, as
+	/// measured by [`unicode-width`](https://crates.io/crates/unicode-width).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// let glue = Conjunction::Other("和"); // Chinese "and".
+	/// assert_eq!(glue.len(), 3);
+	/// assert_eq!(glue.char_len(), 1);
+	/// assert_eq!(glue.display_width(), 2);
+	/// ```
+	pub fn display_width(&self) -> usize {
+		unicode_width::UnicodeWidthStr::width(self.as_str())
+	}
+
+	#[cfg(feature = "i18n")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "i18n")))]
+	#[must_use]
+	/// # For Locale.
+	///
+	/// Return the default "and" for the language of a BCP-47 tag like `"es"`
+	/// or `"pt-BR"`. Only the primary language subtag is considered, and
+	/// case is ignored.
+	///
+	/// Unknown or unsupported languages fall back to [`Conjunction::And`].
+	///
+	/// This covers the common case of picking the right word, but is no
+	/// substitute for full internationalization; some languages vary their
+	/// conjunctions by context, and some don't use Oxford Commas at all.
 	///
 	/// ## Examples
 	///
 	/// ```
 	/// use oxford_join::{Conjunction, OxfordJoin};
 	///
-	/// let set = ["Apples", "Oranges"];
-	/// assert_eq!(set.oxford_join(Conjunction::Nor), set.oxford_nor());
+	/// assert_eq!(Conjunction::for_locale("es"), Conjunction::Other("y"));
+	/// assert_eq!(Conjunction::for_locale("fr-CA"), Conjunction::Other("et"));
+	/// assert_eq!(Conjunction::for_locale("DE_at"), Conjunction::Other("und"));
+	/// assert_eq!(Conjunction::for_locale("en-US"), Conjunction::And);
+	/// assert_eq!(Conjunction::for_locale("tlh"), Conjunction::And);
+	///
+	/// assert_eq!(
+	///     ["Äpfel", "Birnen"].oxford_join(Conjunction::for_locale("de")),
+	///     "Äpfel und Birnen",
+	/// );
+	/// ```
+	pub fn for_locale(tag: &str) -> Conjunction<'static> {
+		/// # Language/Conjunction Pairs.
+		const LOCALES: [(&str, &str); 22] = [
+			("ca", "i"),
+			("cs", "a"),
+			("da", "og"),
+			("de", "und"),
+			("es", "y"),
+			("fi", "ja"),
+			("fr", "et"),
+			("hu", "és"),
+			("id", "dan"),
+			("it", "e"),
+			("ms", "dan"),
+			("nb", "og"),
+			("nl", "en"),
+			("nn", "og"),
+			("no", "og"),
+			("pl", "i"),
+			("pt", "e"),
+			("ro", "și"),
+			("ru", "и"),
+			("sk", "a"),
+			("sv", "och"),
+			("tr", "ve"),
+		];
+
+		let lang = tag.split(['-', '_']).next().unwrap_or_default().trim();
+		LOCALES.iter()
+			.find(|(k, _)| k.eq_ignore_ascii_case(lang))
+			.map_or(Conjunction::And, |(_, v)| Conjunction::Other(v))
+	}
+
+	#[must_use]
+	/// # Is Empty.
+	///
+	/// An empty conjunction makes no sense, but because [`Conjunction::Other`]
+	/// and [`Conjunction::OtherRaw`] wrap arbitrary values, it is worth
+	/// checking. (The latter is measured by its trimmed `pair` value, as with
+	/// [`Conjunction::len`].)
+	///
+	/// This is consistent with [`Conjunction::len`], so is also `true` for
+	/// [`Conjunction::Dynamic`], which has no fixed representation.
+	pub const fn is_empty(&self) -> bool { self.len() == 0 }
+
+	#[must_use]
+	/// # Semantic Equality.
+	///
+	/// Returns `true` if the two conjunctions render identically in a join,
+	/// regardless of variant, e.g. [`Conjunction::And`] and
+	/// `Conjunction::Other("and")`.
+	///
+	/// This is separate from [`PartialEq`], which is structural, so that the
+	/// `Eq`/`Hash` contracts are unaffected. It is mostly useful for deduping
+	/// conjunctions collected from mixed sources.
+	///
+	/// [`Conjunction::Dynamic`] values have no fixed output, so are only
+	/// semantically equal to themselves (by function address).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// assert_ne!(Conjunction::And, Conjunction::Other("and"));
+	/// assert!(Conjunction::And.semantic_eq(&Conjunction::Other("and")));
+	///
+	/// // Raw conjunctions count too, if they match both ways.
+	/// let raw = Conjunction::OtherRaw { pair: " & ", list: ", & " };
+	/// assert!(Conjunction::Ampersand.semantic_eq(&raw));
+	///
+	/// let raw = Conjunction::OtherRaw { pair: " & ", list: " & " };
+	/// assert!(! Conjunction::Ampersand.semantic_eq(&raw));
+	/// ```
+	pub fn semantic_eq(&self, other: &Conjunction<'_>) -> bool {
+		/// # Same Parts?
+		fn same(a: [&str; 3], b: [&str; 3]) -> bool {
+			a.iter().flat_map(|s| s.bytes()).eq(b.iter().flat_map(|s| s.bytes()))
+		}
+
+		match (self, other) {
+			(Self::Dynamic(a), Conjunction::Dynamic(b)) =>
+				core::ptr::eq(*a as *const (), *b as *const ()),
+			(Self::Dynamic(_), _) | (_, Conjunction::Dynamic(_)) => false,
+			_ =>
+				same(self.pair_parts(), other.pair_parts()) &&
+				same(self.list_parts(", "), other.list_parts(", ")),
+		}
+	}
+
+	/// # Rank.
+	///
+	/// Return the variant's position, used to break ordering ties between
+	/// conjunctions with the same string representation.
+	const fn rank(&self) -> u8 {
+		match self {
+			Self::Ampersand => 0,
+			Self::And => 1,
+			Self::AndOr => 2,
+			Self::Comma => 3,
+			Self::Nor => 4,
+			Self::Or => 5,
+			Self::Plus => 6,
+			Self::Other(_) => 7,
+			Self::Dynamic(_) => 8,
+			Self::OtherRaw { .. } => 9,
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Conjunction<'_> {
+	/// # Oxford Join (Generic).
+	///
+	/// This convenience method allows you to Oxford-join _any_ iterable data
+	/// source that yields `AsRef<str>`.
+	///
+	/// For types that implement [`OxfordJoin`] directly, the trait methods
+	/// should be preferred as they're specialized, but you'll get the same
+	/// answer either way.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	/// const LIST: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+	///
+	/// // A contrived example to spell it out…
+	/// assert_eq!(
+	///     Conjunction::And.oxford_join("hello".chars().map(String::from)),
+	///     "h, e, l, l, and o"
+	/// );
+	/// ```
+	///
+	/// (For `char`s specifically, [`Conjunction::oxford_join_chars`] can do the
+	/// same thing without allocating a `String` for each one.)
+	pub fn oxford_join<I, T>(&self, iter: I) -> String
+	where T: AsRef<str>, I: IntoIterator<Item=T> {
+		let mut out = String::new();
+		self.oxford_join_into(iter, &mut out);
+		out
+	}
+
+	/// # Oxford Join (Generic, Into).
+	///
+	/// This works just like [`Conjunction::oxford_join`], except the output
+	/// is written to an existing `String`, allowing its allocation to be
+	/// reused across many joins.
+	///
+	/// The buffer is **cleared** before writing, so will only ever contain the
+	/// result of the latest join.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// let mut buf = String::new();
+	/// for set in [["a", "b"], ["c", "d"]] {
+	///     Conjunction::And.oxford_join_into(set, &mut buf);
+	///     println!("{buf}");
+	/// }
+	/// assert_eq!(buf, "c and d");
+	/// ```
+	///
+	/// (To keep the existing contents instead, use
+	/// [`Conjunction::oxford_join_append`].)
+	pub fn oxford_join_into<I, T>(&self, iter: I, out: &mut String)
+	where T: AsRef<str>, I: IntoIterator<Item=T> {
+		out.clear();
+		self.oxford_join_append(iter, out);
+	}
+
+	/// # Oxford Join (Generic, Append).
+	///
+	/// This works just like [`Conjunction::oxford_join_into`], except the
+	/// output is **appended** to the buffer rather than replacing its
+	/// contents, making it easy to stream many joins into one growing
+	/// `String`.
+	///
+	/// Nothing is written for empty iterators.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// let mut buf = String::new();
+	/// for (label, set) in [("Fruit", ["Apples", "Bananas"]), ("Veg", ["Carrots", "Leeks"])] {
+	///     buf.push_str(label);
+	///     buf.push_str(": ");
+	///     Conjunction::And.oxford_join_append(set, &mut buf);
+	///     buf.push('\n');
+	/// }
+	/// assert_eq!(buf, "Fruit: Apples and Bananas\nVeg: Carrots and Leeks\n");
+	/// ```
+	pub fn oxford_join_append<I, T>(&self, iter: I, out: &mut String)
+	where T: AsRef<str>, I: IntoIterator<Item=T> {
+		// Pull the first value, ensuring there actually is one.
+		let mut iter = iter.into_iter();
+		let Some(next) = iter.next() else { return; };
+
+		// One fuzzy preallocation improves collection times a lot compared to
+		// separate item-by-item reserves. If the iterator knows roughly how
+		// many items remain, we can use the first as a model for the rest;
+		// otherwise we'll just have to guess.
+		let next = next.as_ref();
+		let count = iter.size_hint().0.saturating_add(1);
+		out.reserve(self.estimate_len(count, next.len()).max(64)); // MAGIC NUMBER.
+		out.push_str(next);
+
+		// We have a second item!
+		if let Some(mut buf) = iter.next() {
+			// Can we get an Nth?!
+			let mut many = false;
+			for next in iter.map(|n| core::mem::replace(&mut buf, n)) {
+				// Add the _previous_ value to the output. (The "current" value
+				// is now in the buffer.)
+				out.push_str(", ");
+				out.push_str(next.as_ref());
+				many = true;
+			}
+
+			// Add the final punctuation and conjunction.
+			let glue = self.for_last(buf.as_ref());
+			let parts = if many { glue.list_parts(", ") } else { glue.pair_parts() };
+			for p in parts { out.push_str(p); }
+
+			// Cap it off with the last item.
+			out.push_str(buf.as_ref());
+		}
+	}
+
+	/// # Try Oxford Join (Generic).
+	///
+	/// This works just like [`Conjunction::oxford_join`], except the items
+	/// are `Result`s; the `Ok` values are joined, but the first `Err`, if
+	/// any, stops iteration and is returned instead.
+	///
+	/// This saves having to `collect` the results into a temporary `Vec`
+	/// first.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	/// use std::num::NonZeroU8;
+	///
+	/// let nums = ["1", "2", "3"].into_iter().map(|n| n.parse::<NonZeroU8>().map(|_| n));
+	/// assert_eq!(Conjunction::And.try_oxford_join(nums), Ok(String::from("1, 2, and 3")));
+	///
+	/// let nums = ["1", "0", "x"].into_iter().map(|n| n.parse::<NonZeroU8>().map(|_| n));
+	/// assert!(Conjunction::And.try_oxford_join(nums).is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns the first error encountered, if any.
+	pub fn try_oxford_join<I, T, E>(&self, iter: I) -> Result<String, E>
+	where T: AsRef<str>, I: IntoIterator<Item=Result<T, E>> {
+		let mut err = None;
+		let out = self.oxford_join(iter.into_iter().map_while(|r| match r {
+			Ok(v) => Some(v),
+			Err(e) => {
+				err = Some(e);
+				None
+			},
+		}));
+		err.map_or(Ok(out), Err)
+	}
+
+	/// # Oxford Join (Numeric Ranges).
+	///
+	/// Join a slice of numbers, collapsing runs of three or more consecutive
+	/// values into ranges, e.g. `[1, 2, 3, 4, 5, 7]` becomes
+	/// `"1 through 5 and 7"`.
+	///
+	/// The `through` separator is inserted between the ends of each range
+	/// as-is, so include whatever spacing you need: `" through "`, `" to "`,
+	/// `"–"` (en dash), etc.
+	///
+	/// Runs are only detected in ascending order; the numbers are otherwise
+	/// joined in the order given, without sorting or deduplication. Runs of
+	/// two are left as-is, since "1 through 2" is no shorter than "1 and 2".
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// let set = [1_u32, 2, 3, 4, 5, 7];
+	/// assert_eq!(
+	///     Conjunction::And.oxford_join_ranged(&set, " through "),
+	///     "1 through 5 and 7",
+	/// );
+	///
+	/// let set = [1_u32, 2, 3, 4, 5, 7, 8, 10, 11, 12];
+	/// assert_eq!(
+	///     Conjunction::And.oxford_join_ranged(&set, "–"),
+	///     "1–5, 7, 8, and 10–12",
+	/// );
 	/// ```
-	fn oxford_nor(&self) -> Cow<str> { self.oxford_join(Conjunction::Nor) }
+	pub fn oxford_join_ranged<N>(&self, set: &[N], through: &str) -> String
+	where N: Copy + Display + From<u8> + PartialOrd + Sub<Output=N> {
+		let one = N::from(1);
+		let mut parts = Vec::with_capacity(set.len());
+		let mut idx = 0;
+		while idx < set.len() {
+			// Find the end of the run, if any.
+			let mut end = idx;
+			while
+				end + 1 < set.len() &&
+				set[end] < set[end + 1] &&
+				set[end + 1] - one == set[end]
+			{ end += 1; }
+
+			if 2 <= end - idx {
+				parts.push(alloc::format!("{}{through}{}", set[idx], set[end]));
+			}
+			else {
+				parts.extend(set[idx..=end].iter().map(|n| alloc::format!("{n}")));
+			}
+
+			idx = end + 1;
+		}
+
+		self.oxford_join(parts)
+	}
+
+	/// # Oxford Join (Generic, Mapped).
+	///
+	/// This works just like [`Conjunction::oxford_join`], except each item is
+	/// first passed through the provided closure, allowing for on-the-fly
+	/// normalization (trimming, casing, etc.).
+	///
+	/// Because the closure returns a [`Cow`], items needing no changes can
+	/// simply be borrowed, keeping the common case cheap.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	/// use std::borrow::Cow;
+	///
+	/// let set = ["apples", "BANANAS", "carrots"];
+	/// assert_eq!(
+	///     Conjunction::And.oxford_join_map(set, |s| {
+	///         if s.bytes().any(|b| b.is_ascii_lowercase()) {
+	///             Cow::Owned(s.to_ascii_uppercase())
+	///         }
+	///         else { Cow::Borrowed(s) }
+	///     }),
+	///     "APPLES, BANANAS, and CARROTS",
+	/// );
+	/// ```
+	pub fn oxford_join_map<'b, I, T, F>(&self, iter: I, f: F) -> String
+	where I: IntoIterator<Item=T>, F: FnMut(T) -> Cow<'b, str> {
+		self.oxford_join(iter.into_iter().map(f))
+	}
+
+	/// # Oxford Join (Chars).
+	///
+	/// This works just like [`Conjunction::oxford_join`], but for `char`s,
+	/// which are written directly to the output rather than being converted
+	/// to `String`s first.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// assert_eq!(Conjunction::And.oxford_join_chars("hello".chars()), "h, e, l, l, and o");
+	/// assert_eq!(Conjunction::Or.oxford_join_chars(['x', 'y']), "x or y");
+	/// ```
+	pub fn oxford_join_chars<I>(&self, iter: I) -> String
+	where I: IntoIterator<Item=char> {
+		self.oxford_join(iter.into_iter().map(CharStr::from))
+	}
+
+	#[must_use]
+	/// # Oxford Join (Repeated Item).
+	///
+	/// Oxford-join `n` copies of the same item. This is mostly useful for
+	/// generating test fixtures and template placeholders.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// assert_eq!(Conjunction::And.oxford_join_repeat("apple", 0), "");
+	/// assert_eq!(Conjunction::And.oxford_join_repeat("apple", 1), "apple");
+	/// assert_eq!(
+	///     Conjunction::And.oxford_join_repeat("apple", 3),
+	///     "apple, apple, and apple",
+	/// );
+	/// ```
+	pub fn oxford_join_repeat(&self, item: &str, n: usize) -> String {
+		self.oxford_join(core::iter::repeat(item).take(n))
+	}
+
+	#[must_use]
+	/// # Oxford Join (Split String).
+	///
+	/// Split a delimited string — user input, say — on `split`, trim each
+	/// piece, and Oxford-join the non-empty ones.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::Conjunction;
+	///
+	/// assert_eq!(
+	///     Conjunction::And.oxford_join_split("apples, bananas,, carrots ,", ','),
+	///     "apples, bananas, and carrots",
+	/// );
+	/// assert_eq!(
+	///     Conjunction::Or.oxford_join_split("red\n\n  green\n", '\n'),
+	///     "red or green",
+	/// );
+	/// assert_eq!(Conjunction::And.oxford_join_split(" , ", ','), "");
+	/// ```
+	pub fn oxford_join_split(&self, input: &str, split: char) -> String {
+		self.oxford_join(input.split(split).map(str::trim).filter(|s| ! s.is_empty()))
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl Conjunction<'_> {
+	/// # Append for Three+.
+	///
+	/// This writes the conjunction with a leading comma-space and trailing
+	/// space to the buffer, e.g. `", and "`.
+	///
+	/// Dynamic conjunctions should be resolved with [`Conjunction::for_last`]
+	/// beforehand.
+	fn append_to(&self, v: &mut Vec<u8>) {
+		match self {
+			Self::Ampersand => { v.extend_from_slice(b", & "); },
+			Self::And => { v.extend_from_slice(b", and "); },
+			Self::AndOr => { v.extend_from_slice(b", and/or "); },
+			Self::Comma => { v.extend_from_slice(COMMASPACE); },
+			Self::Nor => { v.extend_from_slice(b", nor "); },
+			Self::Or => { v.extend_from_slice(b", or "); },
+			Self::Other(s) => {
+				v.extend_from_slice(COMMASPACE);
+				v.extend_from_slice(s.as_bytes());
+				v.push(b' ');
+			},
+			Self::Dynamic(_) => { v.extend_from_slice(b", "); },
+			Self::OtherRaw { list, .. } => { v.extend_from_slice(list.as_bytes()); },
+			Self::Plus => { v.extend_from_slice(b", + "); },
+		}
+	}
+
+	/// # Append for Two.
+	///
+	/// This writes the conjunction with a leading and trailing space to the
+	/// buffer, e.g. `" and "`.
+	///
+	/// Dynamic conjunctions should be resolved with [`Conjunction::for_last`]
+	/// beforehand.
+	fn append_two(&self, v: &mut Vec<u8>) {
+		match self {
+			Self::Ampersand => { v.extend_from_slice(b" & "); },
+			Self::And => { v.extend_from_slice(b" and "); },
+			Self::AndOr => { v.extend_from_slice(b" and/or "); },
+			Self::Comma => { v.extend_from_slice(COMMASPACE); },
+			Self::Nor => { v.extend_from_slice(b" nor "); },
+			Self::Or => { v.extend_from_slice(b" or "); },
+			Self::Other(s) => {
+				v.push(b' ');
+				v.extend_from_slice(s.as_bytes());
+				v.push(b' ');
+			},
+			Self::Dynamic(_) => { v.push(b' '); },
+			Self::OtherRaw { pair, .. } => { v.extend_from_slice(pair.as_bytes()); },
+			Self::Plus => { v.extend_from_slice(b" + "); },
+		}
+	}
+}
+
+
+
+#[cfg(feature = "alloc")]
+/// # Oxford Join.
+///
+/// Join a slice of strings with Oxford Commas inserted as necessary.
+///
+/// The return formatting depends on the size of the set:
+///
+/// ```text
+/// "" // Zero.
+/// "first" // One.
+/// "first <CONJUNCTION> last" // Two.
+/// "first, second, …, <CONJUNCTION> last" // Three+.
+/// ```
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordJoin};
+///
+/// let set = ["Apples"];
+/// assert_eq!(set.oxford_join(Conjunction::And), "Apples");
+///
+/// let set = ["Apples", "Oranges"];
+/// assert_eq!(set.oxford_join(Conjunction::Or), "Apples or Oranges");
+///
+/// let set = ["Apples", "Oranges", "Bananas"];
+/// assert_eq!(set.oxford_join(Conjunction::AndOr), "Apples, Oranges, and/or Bananas");
+/// ```
+///
+/// Tuples of up to six `AsRef<str>` values are supported too, handy for
+/// joining named variables of different types:
+///
+/// ```
+/// use oxford_join::OxfordJoin;
+///
+/// let fruit = String::from("Bananas");
+/// assert_eq!(("Apples", fruit).oxford_and(), "Apples and Bananas");
+/// ```
+///
+/// Mixed borrowed/owned sets can be collected into `Cow`s, no
+/// [`AsRef`] gymnastics required:
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordJoin};
+/// use std::borrow::Cow;
+///
+/// let raw = ["apples", "BANANAS", "carrots"];
+/// let set: Vec<Cow<str>> = raw.iter()
+///     .map(|s|
+///         if s.bytes().any(|b| b.is_ascii_uppercase()) { Cow::Owned(s.to_lowercase()) }
+///         else { Cow::Borrowed(*s) }
+///     )
+///     .collect();
+/// assert_eq!(set.oxford_and(), "apples, bananas, and carrots");
+///
+/// // The same goes for iterators.
+/// assert_eq!(
+///     Conjunction::Or.oxford_join(set.iter()),
+///     "apples, bananas, or carrots",
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub trait OxfordJoin {
+	/// # Oxford Join.
+	///
+	/// Join a slice of strings with Oxford Commas inserted as necessary.
+	///
+	/// Empty and single-item sets never allocate; they are always returned
+	/// as `Cow::Borrowed`, the latter pointing to the original item. All of
+	/// this crate's implementations uphold this guarantee, and custom ones
+	/// should too.
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str>;
+
+	/// # Item Count.
+	///
+	/// Return the number of items in the set.
+	fn oxford_count(&self) -> usize;
+
+	/// # Walk Items.
+	///
+	/// Pass each item in the set, in order, to the callback.
+	///
+	/// This (and [`OxfordJoin::oxford_count`]) are the low-level building
+	/// blocks behind the trait's more specialized join methods. The number of
+	/// items walked must match the count.
+	///
+	/// For custom types, the [`impl_oxford_join!`](crate::impl_oxford_join)
+	/// macro can generate this (and everything else) for you.
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str));
+
+	/// # Oxford Join (Borrowed).
+	///
+	/// Return the joined set as a borrowed string slice, if possible, without
+	/// needing to specify a [`Conjunction`].
+	///
+	/// Empty and single-item sets — which have nothing to conjunct — always
+	/// return `Some`; larger sets always return `None`, as they can only be
+	/// joined into a new `String`.
+	///
+	/// This formalizes the `Cow::Borrowed` fast paths of
+	/// [`OxfordJoin::oxford_join`], letting you skip the rest of the machinery
+	/// entirely when it isn't needed.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::OxfordJoin;
+	///
+	/// assert_eq!(<[&str; 0]>::default().oxford_borrowed(), Some(""));
+	/// assert_eq!(["Apples"].oxford_borrowed(), Some("Apples"));
+	/// assert_eq!(["Apples", "Oranges"].oxford_borrowed(), None);
+	/// ```
+	fn oxford_borrowed(&self) -> Option<&str> {
+		match self.oxford_count() {
+			0 => Some(""),
+			1 => {
+				let mut out = "";
+				self.oxford_walk(&mut |s| { out = s; });
+				Some(out)
+			},
+			_ => None,
+		}
+	}
+
+	/// # Segment Count.
+	///
+	/// Return the number of discrete visual segments the joined output would
+	/// contain — items, separators, and the conjunction — without actually
+	/// joining anything. This can be useful for pre-measuring layouts, e.g.
+	/// deciding where to wrap lines in a terminal UI.
+	///
+	/// Each item counts as one segment, as does each comma separator and the
+	/// conjunction (if non-empty). Whitespace is not counted:
+	///
+	/// ```text
+	/// 0: ""                                     // 0
+	/// 1: [first]                                // 1
+	/// 2: [first] [and] [last]                   // 3
+	/// n: [first][,] [second][,] [and] [last]    // 2n
+	/// ```
+	///
+	/// [`Conjunction::Comma`] has no conjunction, just separators, and the
+	/// raw glue of [`Conjunction::OtherRaw`] takes the place of the final
+	/// separator rather than following it, so for both, `n` items always
+	/// make `2n - 1` segments.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// assert_eq!(["Apples"].oxford_segments(Conjunction::And), 1);
+	/// assert_eq!(["Apples", "Oranges"].oxford_segments(Conjunction::And), 3);
+	/// assert_eq!(["Apples", "Oranges", "Bananas"].oxford_segments(Conjunction::And), 6);
+	///
+	/// // Empty conjunctions don't count.
+	/// assert_eq!(["Apples", "Oranges"].oxford_segments(Conjunction::Other("")), 2);
+	///
+	/// // Nor do missing ones.
+	/// assert_eq!(["Apples", "Oranges", "Bananas"].oxford_segments(Conjunction::Comma), 5);
+	/// ```
+	fn oxford_segments(&self, glue: Conjunction) -> usize {
+		// Dynamic conjunctions are only "empty" until resolved.
+		let conj = usize::from(
+			matches!(glue, Conjunction::Dynamic(_)) || ! glue.is_empty()
+		);
+		match (self.oxford_count(), glue) {
+			(0, _) => 0,
+			(n, Conjunction::Comma | Conjunction::OtherRaw { .. }) => n * 2 - 1,
+			(1, _) => 1,
+			(2, _) => 2 + conj,
+			(n, _) => n * 2 - 1 + conj,
+		}
+	}
+
+	/// # Oxford Join (Boxed).
+	///
+	/// This works just like [`OxfordJoin::oxford_join`], except the result is
+	/// returned as a `Box<str>` with no spare capacity, making it a better fit
+	/// for long-lived caches.
+	///
+	/// Owned joins are usually allocated exactly to begin with, so the
+	/// conversion is free; borrowed (0/1-item) results are copied into a
+	/// fresh box.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Oranges", "Bananas"];
+	/// let boxed: Box<str> = set.oxford_join_boxed(Conjunction::And);
+	/// assert_eq!(&*boxed, "Apples, Oranges, and Bananas");
+	/// ```
+	fn oxford_join_boxed(&self, glue: Conjunction) -> Box<str> {
+		match self.oxford_join(glue) {
+			Cow::Borrowed(s) => Box::from(s),
+			Cow::Owned(s) => s.into_boxed_str(),
+		}
+	}
+
+	/// # Oxford Join (Checked).
+	///
+	/// This works just like [`OxfordJoin::oxford_join`], but also returns
+	/// `true` if the join allocated, or `false` if it was simply borrowed.
+	///
+	/// This is the same information carried by the [`Cow`] variant itself,
+	/// but is a little more convenient for allocation profiling and the like.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let (out, allocated) = ["Apples"].oxford_join_checked(Conjunction::And);
+	/// assert_eq!(out, "Apples");
+	/// assert!(! allocated);
+	///
+	/// let (out, allocated) = ["Apples", "Bananas"].oxford_join_checked(Conjunction::And);
+	/// assert_eq!(out, "Apples and Bananas");
+	/// assert!(allocated);
+	/// ```
+	fn oxford_join_checked(&self, glue: Conjunction) -> (Cow<'_, str>, bool) {
+		let out = self.oxford_join(glue);
+		let allocated = matches!(out, Cow::Owned(_));
+		(out, allocated)
+	}
+
+	/// # Oxford Join (Non-Empty).
+	///
+	/// This works just like [`OxfordJoin::oxford_join`], except empty
+	/// (zero-length) items are skipped, preventing doubled-up commas and the
+	/// like.
+	///
+	/// Empty items are removed _before_ the formatting rules are applied, so
+	/// the choice between pair and list forms is based on the number of
+	/// non-empty items, e.g. `["a", "", "b"]` becomes `"a and b"`.
+	///
+	/// Whitespace-only items are _not_ empty, and are kept as-is; to drop
+	/// those too, use [`OxfordJoin::oxford_join_trimmed`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "", "Oranges", "", "Bananas"];
+	/// assert_eq!(
+	///     set.oxford_join(Conjunction::And),
+	///     "Apples, , Oranges, , and Bananas",
+	/// );
+	/// assert_eq!(
+	///     set.oxford_join_nonempty(Conjunction::And),
+	///     "Apples, Oranges, and Bananas",
+	/// );
+	///
+	/// // Whitespace counts for something.
+	/// assert_eq!(["a", " ", ""].oxford_join_nonempty(Conjunction::And), "a and  ");
+	/// ```
+	fn oxford_join_nonempty(&self, glue: Conjunction) -> Cow<'_, str> {
+		let set = NonEmpty(self);
+		match set.oxford_count() {
+			0 => Cow::Borrowed(""),
+			1 => {
+				let mut out = "";
+				set.0.oxford_walk(&mut |s| if ! s.is_empty() { out = s; });
+				Cow::Borrowed(out)
+			},
+			_ => {
+				let mut out = String::new();
+				write_styled(&set, JoinStyle::new(glue), &mut out, 0, |_| {});
+				Cow::Owned(out)
+			},
+		}
+	}
+
+	/// # Oxford Join (Trimmed).
+	///
+	/// This works just like [`OxfordJoin::oxford_join_nonempty`], except the
+	/// items that make the cut are also trimmed of any leading and trailing
+	/// whitespace.
+	///
+	/// Items that are empty _after_ trimming are dropped entirely.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = [" Apples", "Oranges ", "  ", " Bananas "];
+	/// assert_eq!(
+	///     set.oxford_join_trimmed(Conjunction::And),
+	///     "Apples, Oranges, and Bananas",
+	/// );
+	///
+	/// assert_eq!(["", " a "].oxford_join_trimmed(Conjunction::And), "a");
+	/// ```
+	fn oxford_join_trimmed(&self, glue: Conjunction) -> Cow<'_, str> {
+		let set = Trimmed(self);
+		match set.oxford_count() {
+			0 => Cow::Borrowed(""),
+			1 => {
+				let mut out = "";
+				set.0.oxford_walk(&mut |s| {
+					let s = s.trim();
+					if ! s.is_empty() { out = s; }
+				});
+				Cow::Borrowed(out)
+			},
+			_ => {
+				let mut out = String::new();
+				write_styled(&set, JoinStyle::new(glue), &mut out, 0, |_| {});
+				Cow::Owned(out)
+			},
+		}
+	}
+
+	/// # Oxford Join (Repeated Conjunction).
+	///
+	/// Join the set with the conjunction between _every_ pair of items —
+	/// and no commas — for emphatic, run-on style lists:
+	///
+	/// ```text
+	/// first <CONJUNCTION> second <CONJUNCTION> … <CONJUNCTION> last
+	/// ```
+	///
+	/// [`Conjunction::Dynamic`] conjunctions are resolved separately for each
+	/// item they precede.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["eggs", "bacon", "toast"];
+	/// assert_eq!(
+	///     set.oxford_join_repeated(Conjunction::And),
+	///     "eggs and bacon and toast",
+	/// );
+	///
+	/// // Pairs are the same either way.
+	/// assert_eq!(
+	///     ["eggs", "bacon"].oxford_join_repeated(Conjunction::Ampersand),
+	///     ["eggs", "bacon"].oxford_join(Conjunction::Ampersand),
+	/// );
+	/// ```
+	fn oxford_join_repeated(&self, glue: Conjunction) -> Cow<'_, str> {
+		// Small sets don't need joining.
+		if let Some(s) = self.oxford_borrowed() { return Cow::Borrowed(s); }
+
+		// Tally up the length.
+		let mut len = 0_usize;
+		let mut first = true;
+		self.oxford_walk(&mut |s| {
+			if first { first = false; }
+			else { len = len.saturating_add(glue.for_last(s).pair_glue_len()); }
+			len = len.saturating_add(s.len());
+		});
+
+		// Write it!
+		let mut out = String::with_capacity(len);
+		let mut first = true;
+		self.oxford_walk(&mut |s| {
+			if first { first = false; }
+			else {
+				for p in glue.for_last(s).pair_parts() { out.push_str(p); }
+			}
+			out.push_str(s);
+		});
+
+		Cow::Owned(out)
+	}
+
+	/// # Oxford Join (Counted).
+	///
+	/// Collapse duplicate items — keeping the first-seen order — and join
+	/// the rest, marking any that appeared more than once with their count:
+	///
+	/// ```text
+	/// "<ITEM> (×<N>)"
+	/// ```
+	///
+	/// That is, a space, an opening parenthesis, a multiplication sign
+	/// (`U+00D7`), the count, and a closing parenthesis. Use
+	/// [`OxfordJoin::oxford_join_counted_with`] for different formatting.
+	///
+	/// Items are compared exactly, via linear scan, so this is best suited to
+	/// small-to-medium sets.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Bananas", "Apples", "Carrots", "Apples"];
+	/// assert_eq!(
+	///     set.oxford_join_counted(Conjunction::And),
+	///     "Apples (×3), Bananas, and Carrots",
+	/// );
+	///
+	/// assert_eq!(["a", "a", "b"].oxford_join_counted(Conjunction::And), "a (×2) and b");
+	/// ```
+	fn oxford_join_counted(&self, glue: Conjunction) -> Cow<'_, str> {
+		self.oxford_join_counted_with(glue, &|n| alloc::format!(" (×{n})"))
+	}
+
+	/// # Oxford Join (Counted, Custom).
+	///
+	/// This works just like [`OxfordJoin::oxford_join_counted`], except the
+	/// count marker is generated by the callback, which receives the count
+	/// — always two or more — and returns the text to append to the item.
+	///
+	/// The callback may capture its environment.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Bananas", "Apples"];
+	/// assert_eq!(
+	///     set.oxford_join_counted_with(Conjunction::And, &|n| format!(" x{n}")),
+	///     "Apples x2 and Bananas",
+	/// );
+	///
+	/// let unit = "crates";
+	/// assert_eq!(
+	///     set.oxford_join_counted_with(Conjunction::And, &|n| format!(" ({n} {unit})")),
+	///     "Apples (2 crates) and Bananas",
+	/// );
+	/// ```
+	fn oxford_join_counted_with(&self, glue: Conjunction, fmt_count: &dyn Fn(usize) -> String)
+	-> Cow<'_, str> {
+		// Tally up the uniques.
+		let mut uniq: Vec<(&str, usize)> = Vec::new();
+		self.oxford_walk(&mut |s| {
+			if let Some(e) = uniq.iter_mut().find(|e| e.0 == s) { e.1 += 1; }
+			else { uniq.push((s, 1)); }
+		});
+
+		// If there were no duplicates, this is just a regular join.
+		if uniq.len() == self.oxford_count() { return self.oxford_join(glue); }
+
+		let set: Vec<Cow<str>> = uniq.into_iter()
+			.map(|(s, n)|
+				if n == 1 { Cow::Borrowed(s) }
+				else { Cow::Owned([s, &fmt_count(n)].concat()) }
+			)
+			.collect();
+		Cow::Owned(set.oxford_join(glue).into_owned())
+	}
+
+	/// # Oxford Join (Prefixed).
+	///
+	/// Join the set like [`OxfordJoin::oxford_join`], but with a fixed
+	/// `prefix` — a currency symbol, say — prepended to every item.
+	///
+	/// The result is allocated exactly once; there's no need to map and
+	/// allocate each item first.
+	///
+	/// Note that [`Conjunction::Dynamic`] selectors receive the last item
+	/// _without_ its prefix.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["3", "5", "2"];
+	/// assert_eq!(set.oxford_join_prefixed(Conjunction::And, "$"), "$3, $5, and $2");
+	/// assert_eq!(set[..1].oxford_join_prefixed(Conjunction::And, "$"), "$3");
+	/// ```
+	fn oxford_join_prefixed(&self, glue: Conjunction, prefix: &str) -> Cow<'_, str> {
+		join_affixed(self, glue, prefix, "")
+	}
+
+	/// # Oxford Join (Suffixed).
+	///
+	/// Join the set like [`OxfordJoin::oxford_join`], but with a fixed
+	/// `suffix` — units, say — appended to every item.
+	///
+	/// The result is allocated exactly once; there's no need to map and
+	/// allocate each item first.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["3", "5", "2"];
+	/// assert_eq!(
+	///     set.oxford_join_suffixed(Conjunction::And, " kg"),
+	///     "3 kg, 5 kg, and 2 kg",
+	/// );
+	/// assert_eq!(set[..2].oxford_join_suffixed(Conjunction::Or, "%"), "3% or 5%");
+	/// ```
+	fn oxford_join_suffixed(&self, glue: Conjunction, suffix: &str) -> Cow<'_, str> {
+		join_affixed(self, glue, "", suffix)
+	}
+
+	/// # Oxford Join (Etc.).
+	///
+	/// Join _all_ items with commas, then append a trailing terminator like
+	/// `"etc."` or `"and so on"` in place of a conjunction:
+	///
+	/// ```text
+	/// ""                           // Zero.
+	/// "first, <ETC>"               // One.
+	/// "first, second, …, <ETC>"    // Two+.
+	/// ```
+	///
+	/// Unlike [`OxfordJoin::oxford_join_clamped`], nothing is cut; every item
+	/// is kept, and the terminator simply implies there are _more_ that went
+	/// unlisted. That holds just as well for a single item, so it gets one
+	/// too. Empty sets, however, remain empty; there's nothing to _etc._ in
+	/// that case.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::OxfordJoin;
+	///
+	/// let set = ["Apples", "Bananas", "Carrots"];
+	/// assert_eq!(set.oxford_join_etc("etc."), "Apples, Bananas, Carrots, etc.");
+	/// assert_eq!(set[..1].oxford_join_etc("etc."), "Apples, etc.");
+	/// assert_eq!(set[..0].oxford_join_etc("etc."), "");
+	/// ```
+	fn oxford_join_etc(&self, etc: &str) -> Cow<'_, str> {
+		let count = self.oxford_count();
+		if count == 0 { return Cow::Borrowed(""); }
+
+		// Every item is followed by a comma-space.
+		let mut len = count.saturating_mul(2).saturating_add(etc.len());
+		self.oxford_walk(&mut |s| { len = len.saturating_add(s.len()); });
+
+		let mut out = String::with_capacity(len);
+		self.oxford_walk(&mut |s| {
+			out.push_str(s);
+			out.push_str(", ");
+		});
+		out.push_str(etc);
+
+		Cow::Owned(out)
+	}
+
+	#[inline]
+	/// # Oxford Join (Styled).
+	///
+	/// Join the set using a custom [`JoinStyle`], allowing for separators
+	/// other than the usual comma-space.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{JoinStyle, OxfordJoin};
+	///
+	/// // Pairs are unaffected by the separator.
+	/// let set = ["Apples", "Oranges"];
+	/// assert_eq!(
+	///     set.oxford_join_styled(JoinStyle::SEMICOLON_AND),
+	///     "Apples and Oranges",
+	/// );
+	///
+	/// // Larger sets are.
+	/// let set = ["Apples", "Oranges", "Bananas"];
+	/// assert_eq!(
+	///     set.oxford_join_styled(JoinStyle::SEMICOLON_AND),
+	///     "Apples; Oranges; and Bananas",
+	/// );
+	/// ```
+	fn oxford_join_styled(&self, style: JoinStyle) -> Cow<'_, str> {
+		join_styled(self, style)
+	}
+
+	#[inline]
+	/// # Oxford Join (Custom).
+	///
+	/// Join the set with a custom item separator (`sep`), pre-conjunction
+	/// separator (`last_sep`), and conjunction, for house styles like
+	/// `"first; second — and third"`.
+	///
+	/// This is shorthand for [`OxfordJoin::oxford_join_styled`] with a
+	/// [`JoinStyle`] using [`JoinStyle::with_separator`] and
+	/// [`JoinStyle::with_last_separator`]. As usual, the separators are only
+	/// used for sets with three or more items.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Bananas", "Carrots"];
+	/// assert_eq!(
+	///     set.oxford_join_custom(", ", " — ", Conjunction::And),
+	///     "Apples, Bananas — and Carrots",
+	/// );
+	/// assert_eq!(
+	///     set.oxford_join_custom("; ", ";\n", Conjunction::Or),
+	///     "Apples; Bananas;\nor Carrots",
+	/// );
+	///
+	/// // Pairs just get the conjunction.
+	/// assert_eq!(
+	///     set[..2].oxford_join_custom("; ", " — ", Conjunction::And),
+	///     "Apples and Bananas",
+	/// );
+	/// ```
+	fn oxford_join_custom(&self, sep: &str, last_sep: &str, glue: Conjunction)
+	-> Cow<'_, str> {
+		join_styled(self, JoinStyle::new(glue).with_separator(sep).with_last_separator(last_sep))
+	}
+
+	/// # Oxford Join (Final Separator).
+	///
+	/// Join the set like [`OxfordJoin::oxford_join`], but with a different
+	/// mark — like an em dash — in place of the comma immediately preceding
+	/// the conjunction in sets with three or more items:
+	///
+	/// ```text
+	/// first and last
+	/// first, second {FINAL_SEP} and last
+	/// ```
+	///
+	/// The mark is padded with a single space on either side. Pairs are
+	/// unaffected.
+	///
+	/// (For full control over the punctuation and whitespace, use
+	/// [`OxfordJoin::oxford_join_custom`] instead.)
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Bananas", "Carrots"];
+	/// assert_eq!(
+	///     set.oxford_join_final_sep(Conjunction::And, "—"),
+	///     "Apples, Bananas — and Carrots",
+	/// );
+	/// assert_eq!(
+	///     set[..2].oxford_join_final_sep(Conjunction::And, "—"),
+	///     "Apples and Bananas",
+	/// );
+	/// ```
+	fn oxford_join_final_sep(&self, glue: Conjunction, final_sep: &str)
+	-> Cow<'_, str> {
+		if self.oxford_count() < 3 { self.oxford_join(glue) }
+		else {
+			let last_sep = [" ", final_sep, " "].concat();
+			join_styled(self, JoinStyle::new(glue).with_last_separator(&last_sep))
+		}
+	}
+
+	/// # Oxford Join (With Spans).
+	///
+	/// Join the set like [`OxfordJoin::oxford_join`], but also return the
+	/// byte range each item occupies within the result, in order.
+	///
+	/// This is useful for attaching links, styles, etc., to individual
+	/// entries after the fact, without having to search the output (which is
+	/// error-prone when items repeat or contain commas).
+	///
+	/// Note that the result is always owned, even for empty and single-item
+	/// sets.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Oranges", "Bananas"];
+	/// let (out, spans) = set.oxford_join_spans(Conjunction::And);
+	/// assert_eq!(out, "Apples, Oranges, and Bananas");
+	/// assert_eq!(spans, [0..6, 8..15, 21..28]);
+	/// assert_eq!(&out[spans[2].clone()], "Bananas");
+	/// ```
+	fn oxford_join_spans(&self, glue: Conjunction) -> (String, Vec<Range<usize>>) {
+		let mut out = String::new();
+		let mut spans = Vec::with_capacity(self.oxford_count());
+		write_styled(self, JoinStyle::new(glue), &mut out, 0, |r| spans.push(r));
+		(out, spans)
+	}
+
+	/// # Oxford Join (With Last Span).
+	///
+	/// This works just like [`OxfordJoin::oxford_join_spans`], but only
+	/// returns the byte range of the _last_ item — not counting the
+	/// conjunction — saving the `Vec` when that's the only one you need.
+	///
+	/// Empty sets return an empty range.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Oranges", "Apples"];
+	/// let (out, span) = set.oxford_join_spanned(Conjunction::And);
+	/// assert_eq!(out, "Apples, Oranges, and Apples");
+	/// assert_eq!(span, 21..27);
+	/// assert_eq!(&out[span], "Apples");
+	/// ```
+	fn oxford_join_spanned(&self, glue: Conjunction) -> (String, Range<usize>) {
+		let mut out = String::new();
+		let mut span = 0..0;
+		write_styled(self, JoinStyle::new(glue), &mut out, 0, |r| { span = r; });
+		(out, span)
+	}
+
+	/// # Oxford Join (Split).
+	///
+	/// Join the set like [`OxfordJoin::oxford_join`], but return the last item
+	/// separately from everything before it — including the commas and
+	/// conjunction — so the two can be styled differently.
+	///
+	/// Concatenating the parts always reproduces the regular join:
+	///
+	/// ```text
+	/// 0: ("", None)
+	/// 1: ("", Some("first"))
+	/// 2: ("first <CONJUNCTION> ", Some("last"))
+	/// n: ("first, second, …, <CONJUNCTION> ", Some("last"))
+	/// ```
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Oranges", "Bananas"];
+	/// let (head, last) = set.oxford_split(Conjunction::And);
+	/// assert_eq!(head, "Apples, Oranges, and ");
+	/// assert_eq!(last, Some("Bananas"));
+	///
+	/// // Embolden the last one!
+	/// assert_eq!(
+	///     format!("{head}<b>{}</b>", last.unwrap_or_default()),
+	///     "Apples, Oranges, and <b>Bananas</b>",
+	/// );
+	/// ```
+	fn oxford_split(&self, glue: Conjunction) -> (Cow<'_, str>, Option<&str>) {
+		let mut last = None;
+		self.oxford_walk(&mut |s| { last = Some(s); });
+
+		// We only need to join if there are two or more items.
+		if self.oxford_count() < 2 { return (Cow::Borrowed(""), last); }
+
+		// Join everything, then chop off the last item.
+		let mut head = String::new();
+		let mut start = 0;
+		write_styled(self, JoinStyle::new(glue), &mut head, 0, |r| { start = r.start; });
+		head.truncate(start);
+		(Cow::Owned(head), last)
+	}
+
+	/// # Oxford Join (Sentence Case).
+	///
+	/// Join the set like [`OxfordJoin::oxford_join`], but with the first
+	/// character upper-cased, for lists that begin a sentence. Everything
+	/// else is left as-is.
+	///
+	/// Multi-byte characters — including those whose uppercase forms are
+	/// longer than the original, like `'ŉ'` — are handled correctly, and the
+	/// output is allocated just once.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["apples", "oranges", "bananas"];
+	/// assert_eq!(
+	///     set.oxford_join_sentence_case(Conjunction::And),
+	///     "Apples, oranges, and bananas",
+	/// );
+	///
+	/// let set = ["éclairs", "tarts"];
+	/// assert_eq!(
+	///     set.oxford_join_sentence_case(Conjunction::Or),
+	///     "Éclairs or tarts",
+	/// );
+	/// ```
+	fn oxford_join_sentence_case(&self, glue: Conjunction) -> Cow<'_, str> {
+		// Find the first character of the first item.
+		let mut first = None;
+		let mut idx = 0;
+		self.oxford_walk(&mut |s| {
+			if idx == 0 { first = s.chars().next(); }
+			idx += 1;
+		});
+
+		// If there isn't one, or it is already uppercase, there's nothing
+		// special to do.
+		let Some(first) = first.filter(|c| ! c.to_uppercase().eq(core::iter::once(*c)))
+		else { return self.oxford_join(glue); };
+
+		// Encode the uppercase version — at most three chars — on the stack.
+		let mut upper = [0_u8; 12];
+		let mut new_len = 0;
+		for c in first.to_uppercase() {
+			new_len += c.encode_utf8(&mut upper[new_len..]).len();
+		}
+		let upper = core::str::from_utf8(&upper[..new_len]).unwrap_or_default();
+
+		// Join with enough extra room for the conversion, then swap out the
+		// first character in one go.
+		let old_len = first.len_utf8();
+		let mut out = String::new();
+		write_styled(
+			self,
+			JoinStyle::new(glue),
+			&mut out,
+			new_len.saturating_sub(old_len),
+			|_| {},
+		);
+		out.replace_range(..old_len, upper);
+
+		Cow::Owned(out)
+	}
+
+	/// # Oxford Join (Clamped).
+	///
+	/// Join the set like [`OxfordJoin::oxford_join`], but if the result would
+	/// be longer than `max_bytes`, join only as many leading items as fit —
+	/// comma-separated — and append an ellipsis (`"…"`), keeping the total
+	/// within the limit.
+	///
+	/// Items are kept or dropped whole. Only if the first item is too long
+	/// on its own is it cut short, and then always at a `char` boundary, so
+	/// multi-byte UTF-8 sequences will never be split. Note that the ellipsis
+	/// itself is three bytes long; if `max_bytes` is too small to fit it, an
+	/// empty string is returned.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Oranges", "Bananas"];
+	///
+	/// // Everything fits.
+	/// assert_eq!(
+	///     set.oxford_join_clamped(Conjunction::And, 80),
+	///     "Apples, Oranges, and Bananas",
+	/// );
+	///
+	/// // Not everything fits.
+	/// let out = set.oxford_join_clamped(Conjunction::And, 20);
+	/// assert_eq!(out, "Apples, Oranges…");
+	/// assert!(out.len() <= 20);
+	///
+	/// // Items are kept or dropped whole…
+	/// assert_eq!(set.oxford_join_clamped(Conjunction::And, 16), "Apples…");
+	///
+	/// // …unless the first one is too big on its own.
+	/// assert_eq!(set.oxford_join_clamped(Conjunction::And, 6), "App…");
+	/// ```
+	fn oxford_join_clamped(&self, glue: Conjunction, max_bytes: usize)
+	-> Cow<'_, str> {
+		self.oxford_join_clamped_with(glue, max_bytes, TruncStyle::DEFAULT)
+	}
+
+	/// # Oxford Join (Clamped, Styled).
+	///
+	/// This works just like [`OxfordJoin::oxford_join_clamped`], except the
+	/// ellipsis is taken from the [`TruncStyle`], for localization or
+	/// ASCII-only output. (An empty ellipsis is fine too.)
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin, TruncStyle};
+	///
+	/// let set = ["Apples", "Oranges", "Bananas"];
+	/// let style = TruncStyle::DEFAULT.with_ellipsis(" [...]");
+	/// assert_eq!(
+	///     set.oxford_join_clamped_with(Conjunction::And, 21, style),
+	///     "Apples, Oranges [...]",
+	/// );
+	/// ```
+	fn oxford_join_clamped_with(&self, glue: Conjunction, max_bytes: usize, style: TruncStyle)
+	-> Cow<'_, str> {
+		clamp(self, glue, max_bytes, style.ellipsis(), None)
+	}
+
+	#[cfg(feature = "unicode-width")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "unicode-width")))]
+	/// # Oxford Join (Clamped Display Width).
+	///
+	/// This works just like [`OxfordJoin::oxford_join_clamped`], except the
+	/// limit is measured in terminal columns rather than bytes. If the result
+	/// would be wider than `max_cols`, it is cut short and an ellipsis
+	/// (`"…"`) is appended, keeping the total within the limit.
+	///
+	/// Widths are measured with [`unicode-width`](https://crates.io/crates/unicode-width),
+	/// so wide CJK characters and emoji count as two columns apiece, while
+	/// combining marks and other zero-width characters remain attached to
+	/// whatever precedes them.
+	///
+	/// If `max_cols` is too small to fit even the ellipsis, an empty string
+	/// is returned.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["日本", "🍎", "Apples"];
+	///
+	/// // Everything fits.
+	/// assert_eq!(
+	///     set.oxford_join_clamped_width(Conjunction::And, 20),
+	///     "日本, 🍎, and Apples",
+	/// );
+	///
+	/// // Not everything fits.
+	/// assert_eq!(
+	///     set.oxford_join_clamped_width(Conjunction::And, 10),
+	///     "日本, 🍎…",
+	/// );
+	/// ```
+	fn oxford_join_clamped_width(&self, glue: Conjunction, max_cols: usize)
+	-> Cow<'_, str> {
+		self.oxford_join_clamped_width_with(glue, max_cols, TruncStyle::DEFAULT)
+	}
+
+	#[cfg(feature = "unicode-width")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "unicode-width")))]
+	/// # Oxford Join (Clamped Display Width, Styled).
+	///
+	/// This works just like [`OxfordJoin::oxford_join_clamped_width`], except
+	/// the ellipsis is taken from the [`TruncStyle`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin, TruncStyle};
+	///
+	/// let set = ["日本", "🍎", "Apples"];
+	/// let style = TruncStyle::DEFAULT.with_ellipsis("...");
+	/// assert_eq!(
+	///     set.oxford_join_clamped_width_with(Conjunction::And, 10, style),
+	///     "日本...",
+	/// );
+	/// ```
+	fn oxford_join_clamped_width_with(&self, glue: Conjunction, max_cols: usize, style: TruncStyle)
+	-> Cow<'_, str> {
+		clamp(
+			self,
+			glue,
+			max_cols,
+			style.ellipsis(),
+			Some(|c| unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)),
+		)
+	}
+
+	/// # Oxford Join (Truncated).
+	///
+	/// Join at most `max_items` items, summarizing the rest — if any — with a
+	/// count and the [`TruncStyle`]'s "more" word, which takes the place of
+	/// the last item:
+	///
+	/// ```text
+	/// "first, second, <CONJUNCTION> <N> <MORE>"
+	/// ```
+	///
+	/// Sets with `max_items` or fewer items are joined normally.
+	///
+	/// For tails that don't fit this pattern, like `"(+3)"`, see
+	/// [`OxfordJoin::oxford_join_truncated_with`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin, TruncStyle};
+	///
+	/// let set = ["Apples", "Oranges", "Bananas", "Dates"];
+	/// assert_eq!(
+	///     set.oxford_join_truncated(Conjunction::And, 2, TruncStyle::DEFAULT),
+	///     "Apples, Oranges, and 2 more",
+	/// );
+	/// assert_eq!(
+	///     set.oxford_join_truncated(Conjunction::And, 1, TruncStyle::DEFAULT),
+	///     "Apples and 3 more",
+	/// );
+	/// assert_eq!(
+	///     set.oxford_join_truncated(Conjunction::And, 4, TruncStyle::DEFAULT),
+	///     "Apples, Oranges, Bananas, and Dates",
+	/// );
+	///
+	/// // Localized.
+	/// let style = TruncStyle::DEFAULT.with_more("weitere");
+	/// assert_eq!(
+	///     set.oxford_join_truncated(Conjunction::Other("und"), 2, style),
+	///     "Apples, Oranges, und 2 weitere",
+	/// );
+	/// ```
+	fn oxford_join_truncated(&self, glue: Conjunction, max_items: usize, style: TruncStyle)
+	-> Cow<'_, str> {
+		let count = self.oxford_count();
+		if count <= max_items { return self.oxford_join(glue); }
+
+		let more = count - max_items;
+		let more =
+			if style.more().is_empty() { alloc::format!("{more}") }
+			else { alloc::format!("{more} {}", style.more()) };
+
+		let mut set = Vec::with_capacity(max_items + 1);
+		self.oxford_walk(&mut |s| if set.len() < max_items { set.push(s); });
+		set.push(more.as_str());
+		Cow::Owned(set.oxford_join(glue).into_owned())
+	}
+
+	/// # Oxford Join (Truncated, Custom Tail).
+	///
+	/// This works like [`OxfordJoin::oxford_join_truncated`], except the
+	/// summary of the remaining items is entirely up to you: the first
+	/// `max_items` items are comma-separated, then followed directly by
+	/// whatever `fmt_more` returns for the number of items left out.
+	///
+	/// Since the callback owns the whole tail, it can supply (or skip) the
+	/// comma, conjunction, and spacing however it likes, and can capture
+	/// whatever it needs to do so, like a localized word. Sets with
+	/// `max_items` or fewer items are joined normally.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Oranges", "Bananas", "Dates", "Eggplant"];
+	/// assert_eq!(
+	///     set.oxford_join_truncated_with(Conjunction::And, 2, &|n| format!(" (+{n})")),
+	///     "Apples, Oranges (+3)",
+	/// );
+	/// assert_eq!(
+	///     set.oxford_join_truncated_with(Conjunction::And, 2, &|n| format!("…and {n} others")),
+	///     "Apples, Oranges…and 3 others",
+	/// );
+	/// assert_eq!(
+	///     set.oxford_join_truncated_with(Conjunction::And, 3, &|n| format!(", and {n} more items")),
+	///     "Apples, Oranges, Bananas, and 2 more items",
+	/// );
+	///
+	/// let autres = "autres";
+	/// assert_eq!(
+	///     set.oxford_join_truncated_with(Conjunction::And, 2, &|n| format!(" et {n} {autres}")),
+	///     "Apples, Oranges et 3 autres",
+	/// );
+	///
+	/// // Nothing to truncate.
+	/// assert_eq!(
+	///     set.oxford_join_truncated_with(Conjunction::And, 5, &|n| format!(" (+{n})")),
+	///     "Apples, Oranges, Bananas, Dates, and Eggplant",
+	/// );
+	/// ```
+	fn oxford_join_truncated_with(
+		&self,
+		glue: Conjunction,
+		max_items: usize,
+		fmt_more: &dyn Fn(usize) -> String,
+	) -> Cow<'_, str> {
+		let count = self.oxford_count();
+		if count <= max_items { return self.oxford_join(glue); }
+
+		let mut out = String::new();
+		let mut idx = 0;
+		self.oxford_walk(&mut |s| {
+			if idx < max_items {
+				if idx != 0 { out.push_str(", "); }
+				out.push_str(s);
+				idx += 1;
+			}
+		});
+		out.push_str(&fmt_more(count - max_items));
+		Cow::Owned(out)
+	}
+
+	#[cfg(feature = "html")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "html")))]
+	/// # Oxford Join (HTML).
+	///
+	/// This works just like [`OxfordJoin::oxford_join`], except the items
+	/// _and_ the conjunction are HTML-escaped, making the result safe for use
+	/// in HTML text and (quoted) attribute values.
+	///
+	/// The characters `&`, `<`, `>`, `"`, and `'` are escaped; everything else
+	/// is left as-is.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Salt", "Pepper"];
+	/// assert_eq!(
+	///     set.oxford_join_html(Conjunction::Ampersand),
+	///     "Salt &amp; Pepper",
+	/// );
+	///
+	/// let set = ["<b>", "<i>", "<u>"];
+	/// assert_eq!(
+	///     set.oxford_join_html(Conjunction::Or),
+	///     "&lt;b&gt;, &lt;i&gt;, or &lt;u&gt;",
+	/// );
+	/// ```
+	fn oxford_join_html(&self, glue: Conjunction) -> Cow<'_, str> {
+		// Small sets might not need any changes.
+		if let Some(s) = self.oxford_borrowed() { return escape_html(s); }
+
+		// Find the last item so we can resolve the glue.
+		let count = self.oxford_count();
+		let mut len = 0_usize;
+		let mut last = "";
+		self.oxford_walk(&mut |s| {
+			len = len.saturating_add(s.len());
+			last = s;
+		});
+		let glue = glue.for_last(last);
+
+		// This will grow if anything needs escaping, but should be close.
+		let mut out = String::with_capacity(len.saturating_add(glue.glue_len(count)));
+		let mut idx = 0;
+		self.oxford_walk(&mut |s| {
+			idx += 1;
+			if idx == count {
+				let parts = if count == 2 { glue.pair_parts() } else { glue.list_parts(", ") };
+				for p in parts { out.push_str(&escape_html(p)); }
+			}
+			else if idx != 1 { out.push_str(", "); }
+			out.push_str(&escape_html(s));
+		});
+		Cow::Owned(out)
+	}
+
+	/// # Oxford Join (Progress).
+	///
+	/// Write the joined set directly to `w` — a `String`, `Formatter`, etc. —
+	/// calling `on_item` with each item's (zero-based) index as soon as it has
+	/// been written. This is handy for driving progress bars when joining
+	/// very large sets.
+	///
+	/// Both arguments are trait objects, keeping [`OxfordJoin`] usable as one
+	/// too.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Oranges", "Bananas"];
+	/// let mut out = String::new();
+	/// let mut done = 0;
+	/// set.oxford_join_progress(Conjunction::And, &mut out, &mut |idx| { done = idx + 1; })
+	///     .unwrap();
+	///
+	/// assert_eq!(out, "Apples, Oranges, and Bananas");
+	/// assert_eq!(done, set.len());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// If the writer returns an error, no further items will be written (or
+	/// reported), and the error will be passed through.
+	fn oxford_join_progress(
+		&self,
+		glue: Conjunction,
+		w: &mut dyn core::fmt::Write,
+		on_item: &mut dyn FnMut(usize),
+	) -> core::fmt::Result {
+		// Resolve the glue.
+		let count = self.oxford_count();
+		let glue =
+			if matches!(glue, Conjunction::Dynamic(_)) {
+				let mut last = "";
+				self.oxford_walk(&mut |s| { last = s; });
+				glue.for_last(last)
+			}
+			else { glue };
+
+		let mut idx = 0;
+		let mut res = Ok(());
+		self.oxford_walk(&mut |s| {
+			// Stop writing after the first error.
+			if res.is_err() { return; }
+
+			res =
+				if idx == 0 { w.write_str(s) }
+				else if idx + 1 < count { w.write_str(", ").and_then(|()| w.write_str(s)) }
+				else {
+					let [a, b, c] = if count == 2 { glue.pair_parts() } else { glue.list_parts(", ") };
+					w.write_str(a)
+						.and_then(|()| w.write_str(b))
+						.and_then(|()| w.write_str(c))
+						.and_then(|()| w.write_str(s))
+				};
+
+			if res.is_ok() { on_item(idx); }
+			idx += 1;
+		});
+
+		res
+	}
+
+	#[cfg(feature = "small")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "small")))]
+	#[inline]
+	/// # Oxford Join (Small).
+	///
+	/// Like [`OxfordJoin::oxford_join`], but for allocation-sensitive code:
+	/// output of up to [`SmallJoin::INLINE_CAPACITY`] bytes is built and
+	/// returned on the stack, with only longer joins spilling over to the
+	/// heap.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let out = ["Red", "Green", "Blue"].oxford_join_small(Conjunction::Or);
+	/// assert_eq!(out, "Red, Green, or Blue");
+	/// assert!(out.is_inline());
+	/// ```
+	fn oxford_join_small(&self, glue: Conjunction) -> SmallJoin {
+		SmallJoin::new(self, glue)
+	}
+
+	#[inline]
+	/// # Oxford Join (and).
+	///
+	/// This is equivalent to calling `oxford_join(Conjunction::And)`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Oranges"];
+	/// assert_eq!(set.oxford_join(Conjunction::And), set.oxford_and());
+	/// ```
+	fn oxford_and(&self) -> Cow<'_, str> { self.oxford_join(Conjunction::And) }
+
+	#[inline]
+	/// # Oxford Join (and/or).
+	///
+	/// This is equivalent to calling `oxford_join(Conjunction::AndOr)`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Oranges"];
+	/// assert_eq!(set.oxford_join(Conjunction::AndOr), set.oxford_and_or());
+	/// ```
+	fn oxford_and_or(&self) -> Cow<'_, str> { self.oxford_join(Conjunction::AndOr) }
+
+	#[inline]
+	/// # Oxford Join (nor).
+	///
+	/// This is equivalent to calling `oxford_join(Conjunction::Nor)`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Oranges"];
+	/// assert_eq!(set.oxford_join(Conjunction::Nor), set.oxford_nor());
+	/// ```
+	fn oxford_nor(&self) -> Cow<'_, str> { self.oxford_join(Conjunction::Nor) }
+
+	#[inline]
+	/// # Oxford Join (or).
+	///
+	/// This is equivalent to calling `oxford_join(Conjunction::Or)`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use oxford_join::{Conjunction, OxfordJoin};
+	///
+	/// let set = ["Apples", "Oranges"];
+	/// assert_eq!(set.oxford_join(Conjunction::Or), set.oxford_or());
+	/// ```
+	fn oxford_or(&self) -> Cow<'_, str> { self.oxford_join(Conjunction::Or) }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OxfordJoin for [T] where T: AsRef<str> {
+	#[inline]
+	/// # Oxford Join.
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+		join_indexed(self.len(), &|idx| self[idx].as_ref(), glue)
+	}
+
+	#[inline]
+	fn oxford_count(&self) -> usize { self.len() }
+
+	#[inline]
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+		for v in self { cb(v.as_ref()); }
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OxfordJoin for [T; 0] where T: AsRef<str> {
+	#[inline]
+	/// # Oxford Join.
+	///
+	/// This is a special case; the result is always empty.
+	fn oxford_join(&self, _glue: Conjunction) -> Cow<'_, str> { Cow::Borrowed("") }
+
+	#[inline]
+	fn oxford_count(&self) -> usize { 0 }
+
+	#[inline]
+	fn oxford_walk<'a>(&'a self, _cb: &mut dyn FnMut(&'a str)) {}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OxfordJoin for [T; 1] where T: AsRef<str> {
+	#[inline]
+	/// # Oxford Join.
+	///
+	/// This is a special case; the sole entry will be returned as-is.
+	fn oxford_join(&self, _glue: Conjunction) -> Cow<'_, str> {
+		Cow::Borrowed(self[0].as_ref())
+	}
+
+	#[inline]
+	fn oxford_count(&self) -> usize { 1 }
+
+	#[inline]
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+		cb(self[0].as_ref());
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OxfordJoin for [T; 2] where T: AsRef<str> {
+	#[expect(unsafe_code, reason = "Strings in, strings out.")]
+	#[inline]
+	/// # Oxford Join.
+	///
+	/// This is a special case; it will always read "first CONJUNCTION last".
+	///
+	/// Pairs can never be borrowed, so this always allocates. For hot paths
+	/// where that matters, enable the `small` crate feature and use
+	/// `OxfordJoin::oxford_join_small` to build short results on the stack
+	/// instead.
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+		let glue = glue.for_last(self[1].as_ref());
+		let a = self[0].as_ref().as_bytes();
+		let b = self[1].as_ref().as_bytes();
+
+		let len = a.len().saturating_add(b.len()).saturating_add(glue.pair_glue_len());
+		let mut v = Vec::with_capacity(len);
+		v.extend_from_slice(a);  // First.
+		glue.append_two(&mut v); // Conjunction.
+		v.extend_from_slice(b);  // Last.
+
+		// Safety: strings in, strings out.
+		let out = unsafe { String::from_utf8_unchecked(v) };
+		Cow::Owned(out)
+	}
+
+	#[inline]
+	fn oxford_count(&self) -> usize { 2 }
+
+	#[inline]
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+		cb(self[0].as_ref());
+		cb(self[1].as_ref());
+	}
+}
+
+#[cfg(feature = "alloc")]
+/// # Join Arrays (3+).
+macro_rules! join_arrays {
+	($($num:literal),+ $(,)?) => ($(
+		impl<T> OxfordJoin for [T; $num] where T: AsRef<str> {
+			#[inline]
+			/// # Oxford Join.
+			fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+				join_indexed($num, &|idx| self[idx].as_ref(), glue)
+			}
+
+			#[inline]
+			fn oxford_count(&self) -> usize { $num }
+
+			#[inline]
+			fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+				for v in self { cb(v.as_ref()); }
+			}
+		}
+	)+);
+}
+
+#[cfg(feature = "alloc")]
+join_arrays!(
+	 3,  4,  5,  6,  7,  8,  9, 10, 11, 12, 13, 14, 15, 16, 17,
+	18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+);
+
+#[cfg(feature = "alloc")]
+/// # Helper: Iterator-Based Collection Joins.
+///
+/// This works for any collection with a `len` and a double-ended `$iter`.
+macro_rules! join_btrees {
+	($iter:ident) => (
+		#[expect(unsafe_code, reason = "Strings in, strings out.")]
+		/// # Oxford Join.
+		fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+			match self.len() {
+				0 => Cow::Borrowed(""),
+				1 => Cow::Borrowed(self.$iter().next().unwrap().as_ref()),
+				2 => {
+					let mut iter = self.$iter();
+					let a = iter.next().unwrap().as_ref().as_bytes();
+					let b = iter.next().unwrap().as_ref();
+					let glue = glue.for_last(b);
+					let b = b.as_bytes();
+
+					let len = a.len().saturating_add(b.len()).saturating_add(glue.pair_glue_len());
+					let mut v = Vec::with_capacity(len);
+					v.extend_from_slice(a);  // First.
+					glue.append_two(&mut v); // Conjunction.
+					v.extend_from_slice(b);  // Last.
+
+					// Safety: strings in, strings out.
+					let out = unsafe { String::from_utf8_unchecked(v) };
+					Cow::Owned(out)
+				},
+				n => {
+					let last = n - 1;
+					let glue = glue.for_last(self.$iter().next_back().unwrap().as_ref());
+					let len = self.$iter()
+						.map(|x| x.as_ref().len())
+						.fold(glue.glue_len(n), usize::saturating_add);
+
+					let mut v = Vec::with_capacity(len);
+					let mut iter = self.$iter();
+
+					// Write the first.
+					v.extend_from_slice(iter.next().unwrap().as_ref().as_bytes());
+
+					// Write the middles. (Last is count minus one, but since
+					// we already wrote an entry, we need to subtract one
+					// again.)
+					for s in iter.by_ref().take(last - 1) {
+						v.extend_from_slice(COMMASPACE);
+						v.extend_from_slice(s.as_ref().as_bytes());
+					}
+
+					// Write the conjunction and last.
+					glue.append_to(&mut v);
+					v.extend_from_slice(iter.next().unwrap().as_ref().as_bytes());
+
+					// Safety: strings in, strings out.
+					let out = unsafe { String::from_utf8_unchecked(v) };
+					Cow::Owned(out)
+				},
+			}
+		}
+
+		#[inline]
+		fn oxford_count(&self) -> usize { self.len() }
+
+		#[inline]
+		fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+			for v in self.$iter() { cb(v.as_ref()); }
+		}
+	);
+}
+
+#[cfg(feature = "alloc")]
+impl<K, T> OxfordJoin for BTreeMap<K, T> where T: AsRef<str> { join_btrees!(values); }
+
+#[cfg(feature = "alloc")]
+impl<T> OxfordJoin for BTreeSet<T> where T: AsRef<str> { join_btrees!(iter); }
+
+#[cfg(feature = "alloc")]
+/// # Deques.
+///
+/// The storage of a `VecDeque` might not be contiguous, so this works
+/// through its iterator, same as the binary tree impls, rather than
+/// requiring a `make_contiguous` or `collect` first.
+impl<T> OxfordJoin for VecDeque<T> where T: AsRef<str> { join_btrees!(iter); }
+
+#[cfg(feature = "alloc")]
+/// # Binary Heaps.
+///
+/// Note that heaps are joined in their internal — i.e. _arbitrary_ — order,
+/// not sorted order. Use [`BinaryHeap::into_sorted_vec`] first if that
+/// matters.
+impl<T> OxfordJoin for BinaryHeap<T> where T: AsRef<str> { join_btrees!(iter); }
+
+#[cfg(feature = "alloc")]
+impl<T> OxfordJoin for LinkedList<T> where T: AsRef<str> { join_btrees!(iter); }
+
+#[cfg(feature = "alloc")]
+impl<T> OxfordJoin for Vec<T> where T: AsRef<str> {
+	#[inline]
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+		self.as_slice().oxford_join(glue)
+	}
+
+	#[inline]
+	fn oxford_count(&self) -> usize { self.len() }
+
+	#[inline]
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+		self.as_slice().oxford_walk(cb);
+	}
+}
+
+#[cfg(feature = "alloc")]
+/// # References.
+///
+/// This lets generic code accept `&[T; N]`, `&Vec<T>`, etc., directly.
+impl<S> OxfordJoin for &S where S: OxfordJoin + ?Sized {
+	#[inline]
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> { (**self).oxford_join(glue) }
+
+	#[inline]
+	fn oxford_count(&self) -> usize { (**self).oxford_count() }
+
+	#[inline]
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+		(**self).oxford_walk(cb);
+	}
+}
+
+#[cfg(feature = "alloc")]
+/// # Join Tuples.
+///
+/// Tuples can hold different types, but once referenced as strings, they're
+/// no different than arrays; the latter handle the actual joining.
+macro_rules! join_tuples {
+	($($num:literal ($($ty:ident $idx:tt),+)),+ $(,)?) => ($(
+		impl<$($ty),+> OxfordJoin for ($($ty),+) where $($ty: AsRef<str>),+ {
+			#[inline]
+			/// # Oxford Join.
+			fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+				// Arrays with two or more entries are always owned, so this
+				// doesn't cost anything extra.
+				Cow::Owned([$(self.$idx.as_ref()),+].oxford_join(glue).into_owned())
+			}
+
+			#[inline]
+			fn oxford_count(&self) -> usize { $num }
+
+			#[inline]
+			fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+				$( cb(self.$idx.as_ref()); )+
+			}
+		}
+	)+);
+}
+
+#[cfg(feature = "alloc")]
+join_tuples!(
+	2 (A 0, B 1),
+	3 (A 0, B 1, C 2),
+	4 (A 0, B 1, C 2, D 3),
+	5 (A 0, B 1, C 2, D 3, E 4),
+	6 (A 0, B 1, C 2, D 3, E 4, F 5),
+);
+
+
+
+#[cfg(feature = "alloc")]
+/// # Char As Str.
+///
+/// This holds the UTF-8 encoding of a single `char`, allowing it to be
+/// referenced as a string slice without allocating.
+struct CharStr {
+	/// # Buffer.
+	buf: [u8; 4],
+
+	/// # Length.
+	len: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<str> for CharStr {
+	#[inline]
+	fn as_ref(&self) -> &str {
+		// The buffer always holds exactly one char.
+		core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl From<char> for CharStr {
+	#[inline]
+	fn from(src: char) -> Self {
+		let mut buf = [0_u8; 4];
+		let len = src.encode_utf8(&mut buf).len();
+		Self { buf, len }
+	}
+}
+
+
+
+#[cfg(feature = "alloc")]
+/// # Non-Empty Wrapper.
+///
+/// This wraps an [`OxfordJoin`] set, filtering out any empty items.
+struct NonEmpty<'a, S: ?Sized>(&'a S);
+
+#[cfg(feature = "alloc")]
+impl<S: OxfordJoin + ?Sized> OxfordJoin for NonEmpty<'_, S> {
+	#[inline]
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+		join_styled(self, JoinStyle::new(glue))
+	}
+
+	fn oxford_count(&self) -> usize {
+		let mut count = 0;
+		self.0.oxford_walk(&mut |s| if ! s.is_empty() { count += 1; });
+		count
+	}
+
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+		self.0.oxford_walk(&mut |s| if ! s.is_empty() { cb(s); });
+	}
+}
+
+
+#[cfg(feature = "alloc")]
+/// # Trimmed Wrapper.
+///
+/// This wraps an [`OxfordJoin`] set, trimming each item and filtering out any
+/// that wind up empty.
+struct Trimmed<'a, S: ?Sized>(&'a S);
+
+#[cfg(feature = "alloc")]
+impl<S: OxfordJoin + ?Sized> OxfordJoin for Trimmed<'_, S> {
+	#[inline]
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+		join_styled(self, JoinStyle::new(glue))
+	}
+
+	fn oxford_count(&self) -> usize {
+		let mut count = 0;
+		self.0.oxford_walk(&mut |s| if ! s.trim().is_empty() { count += 1; });
+		count
+	}
+
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+		self.0.oxford_walk(&mut |s| {
+			let s = s.trim();
+			if ! s.is_empty() { cb(s); }
+		});
+	}
+}
+
+
+
+#[cfg(feature = "alloc")]
+#[expect(unsafe_code, reason = "Strings in, strings out.")]
+#[inline(never)]
+/// # Indexed Join.
+///
+/// This is the engine behind the slice and (3+) array implementations.
+///
+/// Items are fetched by index through a trait object rather than a generic,
+/// so the join logic is only compiled once instead of once for every
+/// `AsRef<str>` type (and array length) in use.
+fn join_indexed<'a>(len: usize, get: &dyn Fn(usize) -> &'a str, glue: Conjunction)
+-> Cow<'a, str> {
+	match len {
+		0 => Cow::Borrowed(""),
+		1 => Cow::Borrowed(get(0)),
+		2 => {
+			let first = get(0).as_bytes();
+			let last = get(1);
+			let glue = glue.for_last(last);
+			let last = last.as_bytes();
+
+			let len = first.len().saturating_add(last.len()).saturating_add(glue.pair_glue_len());
+			let mut v = Vec::with_capacity(len);
+			v.extend_from_slice(first); // First.
+			glue.append_two(&mut v);    // Conjunction.
+			v.extend_from_slice(last);  // Last.
+
+			// Safety: strings in, strings out.
+			let out = unsafe { String::from_utf8_unchecked(v) };
+			Cow::Owned(out)
+		},
+		n => {
+			let first = get(0).as_bytes();
+			let last = get(n - 1);
+			let glue = glue.for_last(last);
+			let last = last.as_bytes();
+
+			// Small and medium sets are measured precisely, but for large
+			// ones the extra pass costs more than the odd reallocation, so we
+			// estimate from the ends instead.
+			let len =
+				if ONE_PASS <= n - 2 {
+					glue.estimate_len(n, first.len().saturating_add(last.len()).div_ceil(2))
+				}
+				else {
+					(0..n).map(|idx| get(idx).len())
+						.fold(glue.glue_len(n), usize::saturating_add)
+				};
+			let mut v = Vec::with_capacity(len);
+
+			// Write the first.
+			v.extend_from_slice(first);
+
+			// Write the middles.
+			for idx in 1..n - 1 {
+				v.extend_from_slice(COMMASPACE);
+				v.extend_from_slice(get(idx).as_bytes());
+			}
+
+			// Write the conjunction and last.
+			glue.append_to(&mut v);
+			v.extend_from_slice(last);
+
+			// Estimates can overshoot wildly if the ends are much longer than
+			// the middle; give back the excess if so.
+			if v.len() < v.capacity() / 2 { v.shrink_to_fit(); }
+
+			// Safety: strings in, strings out.
+			let out = unsafe { String::from_utf8_unchecked(v) };
+			Cow::Owned(out)
+		},
+	}
+}
+
+#[cfg(feature = "alloc")]
+/// # Affixed Join.
+///
+/// This is the engine behind [`OxfordJoin::oxford_join_prefixed`] and
+/// [`OxfordJoin::oxford_join_suffixed`], writing `prefix` and `suffix` around
+/// every item as it goes.
+fn join_affixed<'a, S>(set: &'a S, glue: Conjunction, prefix: &str, suffix: &str)
+-> Cow<'a, str>
+where S: OxfordJoin + ?Sized {
+	// Without affixes, this is just a regular join.
+	if prefix.is_empty() && suffix.is_empty() { return set.oxford_join(glue); }
+
+	let count = set.oxford_count();
+	if count == 0 { return Cow::Borrowed(""); }
+
+	// Tally up the length, making note of the last item.
+	let mut len = count.saturating_mul(prefix.len().saturating_add(suffix.len()));
+	let mut last = "";
+	set.oxford_walk(&mut |s| {
+		len = len.saturating_add(s.len());
+		last = s;
+	});
+	let glue = glue.for_last(last);
+	len = len.saturating_add(glue.glue_len(count));
+
+	// Write it!
+	let mut out = String::with_capacity(len);
+	let mut idx = 0;
+	set.oxford_walk(&mut |s| {
+		if idx != 0 {
+			if idx + 1 == count {
+				let parts = if count == 2 { glue.pair_parts() } else { glue.list_parts(", ") };
+				for p in parts { out.push_str(p); }
+			}
+			else { out.push_str(", "); }
+		}
+		out.push_str(prefix);
+		out.push_str(s);
+		out.push_str(suffix);
+		idx += 1;
+	});
+
+	Cow::Owned(out)
+}
+
+#[cfg(feature = "alloc")]
+/// # Styled Join.
+///
+/// This is the generic engine behind [`OxfordJoin::oxford_join_styled`],
+/// built atop the [`OxfordJoin::oxford_count`] and [`OxfordJoin::oxford_walk`]
+/// primitives so it works for any implementing type.
+fn join_styled<'a, S>(set: &'a S, style: JoinStyle) -> Cow<'a, str>
+where S: OxfordJoin + ?Sized {
+	set.oxford_borrowed().map_or_else(
+		|| {
+			let mut out = String::new();
+			write_styled(set, style, &mut out, 0, |_| {});
+			Cow::Owned(out)
+		},
+		Cow::Borrowed,
+	)
+}
+
+#[cfg(feature = "html")]
+/// # Escape HTML.
+///
+/// Escape `&`, `<`, `>`, `"`, and `'` for safe inclusion in HTML, returning
+/// the original if there is nothing to change.
+fn escape_html(src: &str) -> Cow<'_, str> {
+	/// # Escape Character.
+	const fn escape(b: u8) -> Option<&'static str> {
+		match b {
+			b'&' => Some("&amp;"),
+			b'<' => Some("&lt;"),
+			b'>' => Some("&gt;"),
+			b'"' => Some("&quot;"),
+			b'\'' => Some("&#39;"),
+			_ => None,
+		}
+	}
+
+	// Only bother copying if something needs escaping. All of the special
+	// characters are ASCII, so we can safely slice around them.
+	let Some(first) = src.bytes().position(|b| escape(b).is_some()) else {
+		return Cow::Borrowed(src);
+	};
+
+	let mut out = String::with_capacity(src.len() + 16);
+	let mut from = 0;
+	for (idx, b) in src.bytes().enumerate().skip(first) {
+		if let Some(e) = escape(b) {
+			out.push_str(&src[from..idx]);
+			out.push_str(e);
+			from = idx + 1;
+		}
+	}
+	out.push_str(&src[from..]);
+	Cow::Owned(out)
+}
+
+#[cfg(feature = "alloc")]
+/// # Clamp.
+///
+/// Return the Oxford-joined set as-is if its total size is within `max`,
+/// otherwise join as many leading items as will fit — comma-separated, with
+/// room left over for the ellipsis — and append the ellipsis.
+///
+/// Sizes are measured in bytes, or if a `width` callback is provided, by
+/// summing its char-by-char results.
+///
+/// Only if the first item is too big on its own is it truncated, at a char
+/// boundary. Because a char is only dropped when it would put the total over
+/// budget, zero-sized characters (combining marks, etc.) stay with their
+/// base.
+fn clamp<'a, S>(
+	set: &'a S,
+	glue: Conjunction,
+	max: usize,
+	ellipsis: &str,
+	width: Option<fn(char) -> usize>,
+) -> Cow<'a, str>
+where S: OxfordJoin + ?Sized {
+	let measure = |s: &str| width.map_or(s.len(), |w| s.chars().map(w).sum());
+
+	// Return it as-is if it fits.
+	let full = set.oxford_join(glue);
+	if measure(&full) <= max { return full; }
+	drop(full);
+
+	// Make room for the ellipsis, if we can.
+	let Some(budget) = max.checked_sub(measure(ellipsis)) else {
+		return Cow::Borrowed("");
+	};
+
+	// Add whole items until one doesn't fit. The last item is never a
+	// candidate; if it fit, we'd have returned the full join.
+	let mut out = String::new();
+	let mut first = None;
+	let mut used = 0;
+	let mut left = set.oxford_count().saturating_sub(1);
+	set.oxford_walk(&mut |s| {
+		first.get_or_insert(s);
+		if left == 0 { return; }
+		let sep = if out.is_empty() { "" } else { ", " };
+		let need = measure(sep).saturating_add(measure(s));
+		if need <= budget - used {
+			out.push_str(sep);
+			out.push_str(s);
+			used += need;
+			left -= 1;
+		}
+		else { left = 0; }
+	});
+
+	// If not even the first item fit, fall back to a char-boundary cut.
+	if out.is_empty() {
+		let first = first.unwrap_or_default();
+		let cut = width.map_or_else(
+			|| {
+				let mut cut = budget.min(first.len());
+				while ! first.is_char_boundary(cut) { cut -= 1; }
+				cut
+			},
+			|w| {
+				let mut total = 0;
+				first.char_indices()
+					.find_map(|(idx, c)| {
+						total += w(c);
+						(budget < total).then_some(idx)
+					})
+					.unwrap_or(first.len())
+			},
+		);
+		out.push_str(&first[..cut]);
+	}
+
+	out.push_str(ellipsis);
+	Cow::Owned(out)
+}
+
+#[cfg(feature = "alloc")]
+/// # Write Styled Join.
+///
+/// Append the Oxford-joined set to the buffer, reserving the exact amount of
+/// space needed beforehand, plus `extra` bytes for any post-processing the
+/// caller might have in mind.
+///
+/// The byte range of each item — relative to the buffer — is passed to the
+/// callback as it is written.
+fn write_styled<S, F>(
+	set: &S,
+	style: JoinStyle,
+	out: &mut String,
+	extra: usize,
+	mut cb: F,
+)
+where S: OxfordJoin + ?Sized, F: FnMut(Range<usize>) {
+	let count = set.oxford_count();
+	let sep = style.separator();
+	let prefix = if count == 2 { style.pair_prefix() } else { None };
+	let suffix = if count == 2 { style.pair_suffix() } else { None };
+
+	// Tally up the item lengths, making note of the last one.
+	let mut len = 0_usize;
+	let mut last = "";
+	set.oxford_walk(&mut |s| {
+		len = len.saturating_add(s.len());
+		last = s;
+	});
+
+	// Now we can figure out the glue and total length, and allocate just
+	// once.
+	let glue = match style.pair_separator() {
+		Some(pair) if count == 2 => Conjunction::OtherRaw { pair, list: pair },
+		_ => style.conjunction().for_last(last),
+	};
+	let tight = style.is_tight() && glue.is_symbol();
+	len = len.saturating_add(match count {
+		0 | 1 => 0,
+		_ if tight => glue.len().saturating_mul(count - 1),
+		2 => suffix.map_or_else(
+			|| glue.pair_glue_len().saturating_add(prefix.map_or(0, |p| p.len() + 1)),
+			|p| sep.len().saturating_mul(2).saturating_add(p.len()),
+		),
+		_ => {
+			let [a, b, c] = glue.list_parts(style.last_separator());
+			a.len()
+				.saturating_add(b.len())
+				.saturating_add(c.len())
+				.saturating_add(sep.len().saturating_mul(count - 2))
+		},
+	});
+	out.reserve(len.saturating_add(extra));
+
+	// Correlatives go at the very beginning.
+	if let Some(p) = prefix {
+		out.push_str(p);
+		out.push(' ');
+	}
+
+	let mut idx = 0;
+	set.oxford_walk(&mut |s| {
+		// Separators go before everything but the first.
+		if idx != 0 {
+			// Tight symbols go between everything.
+			if tight { out.push_str(glue.as_str()); }
+			// The conjunction goes before the last.
+			else if idx + 1 == count && suffix.is_none() {
+				let parts =
+					if count == 2 { glue.pair_parts() }
+					else { glue.list_parts(style.last_separator()) };
+				for p in parts { out.push_str(p); }
+			}
+			else { out.push_str(sep); }
+		}
+
+		let start = out.len();
+		out.push_str(s);
+		cb(start..out.len());
+		idx += 1;
+	});
+
+	// Or both?
+	if let Some(p) = suffix {
+		out.push_str(sep);
+		out.push_str(p);
+	}
+}
+
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	use super::*;
+	use brunch as _;
+	use into::IntoOxfordJoin;
+
+	const CTEST: [Conjunction; 8] = [
+		Conjunction::Ampersand,
+		Conjunction::And,
+		Conjunction::AndOr,
+		Conjunction::Comma,
+		Conjunction::Nor,
+		Conjunction::Or,
+		Conjunction::Other("Boo"),
+		Conjunction::Plus,
+	];
+
+	#[expect(clippy::redundant_pub_crate, reason = "Shared with the other modules' tests.")]
+	/// # Fruit.
+	///
+	/// A (sorted) set shared by the cross-checks.
+	pub(crate) const FRUIT: [&str; 5] = ["Apples", "Bananas", "Carrots", "Dates", "Eggplant"];
+
+	#[expect(clippy::redundant_pub_crate, reason = "Shared with the other modules' tests.")]
+	/// # Cross-Check.
+	///
+	/// Pass every leading subset of [`FRUIT`] to the callback, once for each
+	/// preset conjunction and a few custom ones, and make sure its output
+	/// matches the regular slice join.
+	///
+	/// Joins that aren't equivalent by design — reversed, truncated, etc. —
+	/// should perform their own checks instead.
+	pub(crate) fn cross_check<F, S>(mut cb: F)
+	where F: FnMut(&'static [&'static str], Conjunction<'static>) -> S, S: AsRef<str> {
+		const SET: &[&str] = &FRUIT;
+		for c in Conjunction::ALL.into_iter().chain([
+			Conjunction::Other("und"),
+			Conjunction::OtherRaw { pair: "→", list: " ⇒ " },
+			Conjunction::Dynamic(|s| if s.starts_with('D') { "y" } else { "e" }),
+		]) {
+			for len in 0..=SET.len() {
+				let set = &SET[..len];
+				let out = cb(set, c);
+				assert_eq!(out.as_ref(), set.oxford_join(c), "Mismatch: {c:?} x {len}.");
+			}
+		}
+	}
+
+	#[test]
+	#[allow(clippy::cognitive_complexity)] // It is what it is.
+	fn t_fruit() {
+		use alloc::string::ToString;
+
+		// Make sure arrays, slices, vecs, boxes, etc., all work out the same
+		// way.
+		macro_rules! compare {
+			($($arr:ident, $expected:literal),+ $(,)?) => ($(
+				assert_eq!($arr.oxford_and(), $expected, "Array.");
+				assert_eq!($arr.as_slice().oxford_and(), $expected, "Slice.");
+
+				let v = $arr.to_vec();
+				assert_eq!(v.oxford_and(), $expected, "Vec.");
+				assert_eq!(v.into_boxed_slice().oxford_and(), $expected, "Box.");
+
+				let v: BTreeMap<usize, &str> = $arr.into_iter().enumerate().collect();
+				assert_eq!(v.oxford_and(), $expected, "BTreeMap.");
+
+				let v = BTreeSet::from($arr);
+				assert_eq!(v.oxford_and(), $expected, "BTreeSet.");
+
+				let v = VecDeque::from($arr);
+				assert_eq!(v.oxford_and(), $expected, "VecDeque.");
+
+				let v = LinkedList::from($arr);
+				assert_eq!(v.oxford_and(), $expected, "LinkedList.");
+
+				// Heaps are unordered, so can only be compared with themselves.
+				let v = BinaryHeap::from($arr);
+				assert_eq!(v.oxford_and(), v.as_slice().oxford_and(), "BinaryHeap.");
+				assert_eq!(
+					BinaryHeap::from($arr).into_sorted_vec().oxford_and(),
+					BTreeSet::from($arr).oxford_and(),
+					"BinaryHeap (sorted).",
+				);
+
+				assert_eq!(
+					OxfordJoinFmt::and($arr.as_slice()).to_string(),
+					$expected,
+					"OxfordJoinFmt::to_string",
+				);
+
+				// Borrowing is only possible for small sets.
+				let borrowed = if $arr.len() < 2 { Some($expected) } else { None };
+				assert_eq!($arr.oxford_borrowed(), borrowed, "Array (borrowed).");
+				assert_eq!($arr.as_slice().oxford_borrowed(), borrowed, "Slice (borrowed).");
+				assert_eq!(
+					BTreeSet::from($arr).oxford_borrowed(),
+					borrowed,
+					"BTreeSet (borrowed).",
+				);
+			)+);
+		}
+
+		const ARR0: [&str; 0] = [];
+		const ARR1: [&str; 1] = ["Apples"];
+		const ARR2: [&str; 2] = ["Apples", "Bananas"];
+		const ARR3: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		const ARR4: [&str; 4] = ["Apples", "Bananas", "Carrots", "Dates"];
+		const ARR5: [&str; 5] = FRUIT;
+		const ARR32: [&str; 32] = [
+			"0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "A", "B", "C", "D", "E", "F",
+			"G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S", "T", "U", "V",
+		];
+
+		compare!(
+			ARR0, "",
+			ARR1, "Apples",
+			ARR2, "Apples and Bananas",
+			ARR3, "Apples, Bananas, and Carrots",
+			ARR4, "Apples, Bananas, Carrots, and Dates",
+			ARR5, "Apples, Bananas, Carrots, Dates, and Eggplant",
+			ARR32, "0, 1, 2, 3, 4, 5, 6, 7, 8, 9, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, and V",
+		);
+	}
+
+	#[test]
+	fn t_no_alloc() {
+		/// # Borrowed?
+		///
+		/// Make sure the output is borrowed and, if non-empty, points to the
+		/// original.
+		fn check<S: OxfordJoin + ?Sized>(set: &S, orig: &str) {
+			for c in CTEST {
+				let out = set.oxford_join(c);
+				let Cow::Borrowed(out) = out else { panic!("Unexpected allocation."); };
+				assert_eq!(out, orig);
+				if ! orig.is_empty() { assert!(core::ptr::eq(out, orig), "Item was copied."); }
+			}
+			assert!(matches!(set.oxford_and(), Cow::Borrowed(_)));
+			assert!(matches!(set.oxford_join_nonempty(Conjunction::And), Cow::Borrowed(_)));
+			assert!(matches!(set.oxford_join_styled(JoinStyle::SEMICOLON_AND), Cow::Borrowed(_)));
+		}
+
+		let item = String::from("Apples");
+		let one: [&str; 1] = [item.as_str()];
+		let empty: [&str; 0] = [];
+
+		// Arrays.
+		check(&empty, "");
+		check(&one, &item);
+
+		// Slices.
+		check(&empty[..], "");
+		check(&one[..], &item);
+
+		// Vecs.
+		check(&Vec::<&str>::new(), "");
+		check(&one.to_vec(), &item);
+
+		// References.
+		check(&&one, &item);
+		check(&&one[..], &item);
+
+		// BTrees.
+		check(&BTreeSet::<&str>::new(), "");
+		check(&BTreeSet::from(one), &item);
+		check(&BTreeMap::<usize, &str>::new(), "");
+		check(&BTreeMap::from([(0_usize, item.as_str())]), &item);
+
+		// Deques.
+		check(&VecDeque::<&str>::new(), "");
+		check(&VecDeque::from(one), &item);
+
+		// Lists and heaps.
+		check(&LinkedList::<&str>::new(), "");
+		check(&LinkedList::from(one), &item);
+		check(&BinaryHeap::<&str>::new(), "");
+		check(&BinaryHeap::from(one), &item);
+	}
+
+	#[test]
+	fn t_vec_deque() {
+		cross_check(|set, c| {
+			// Build a deque that wraps around its buffer.
+			let mut deque = VecDeque::with_capacity(FRUIT.len());
+			deque.extend(["x", "y", "z"]);
+			while deque.pop_front().is_some() {}
+			deque.extend(set);
+			if 2 < set.len() { assert!(! deque.as_slices().1.is_empty(), "Deque is contiguous."); }
+
+			let out = deque.oxford_join(c).into_owned();
+			assert_eq!(out.len(), out.capacity());
+			out
+		});
+	}
+
+	#[test]
+	fn t_references() {
+		/// # Generic Join.
+		fn join<S: OxfordJoin>(set: S) -> String { set.oxford_and().into_owned() }
+
+		const EXPECTED: &str = "Apples, Bananas, and Carrots";
+		let arr = ["Apples", "Bananas", "Carrots"];
+		let vec = arr.to_vec();
+		let set = BTreeSet::from(arr);
+
+		// Owned.
+		assert_eq!(join(arr), EXPECTED);
+		assert_eq!(join(vec.clone()), EXPECTED);
+
+		// Borrowed.
+		let arr_ref: &[&str; 3] = &arr;
+		let vec_ref: &Vec<&str> = &vec;
+		let slice_ref: &&[&str] = &vec.as_slice();
+		let set_ref: &BTreeSet<&str> = &set;
+		assert_eq!(join(arr_ref), EXPECTED);
+		assert_eq!(join(vec_ref), EXPECTED);
+		assert_eq!(join(slice_ref), EXPECTED);
+		assert_eq!(join(set_ref), EXPECTED);
+		assert_eq!(vec.oxford_count(), 3);
+		assert_eq!(OxfordJoin::oxford_count(&arr_ref), 3);
+	}
+
+	#[test]
+	fn t_tuples() {
+		use alloc::{
+			boxed::Box,
+			borrow::ToOwned,
+		};
+
+		let b = "Bananas".to_owned();
+		let d = Cow::Borrowed("Dates");
+		let f = Box::<str>::from("Figs");
+		for (set, expected) in [
+			(("Apples", &b).oxford_and(), "Apples and Bananas"),
+			(("Apples", &b, "Carrots").oxford_and(), "Apples, Bananas, and Carrots"),
+			(("Apples", &b, "Carrots", &d).oxford_and(), "Apples, Bananas, Carrots, and Dates"),
+			(("Apples", &b, "Carrots", &d, "Eggplant").oxford_and(), "Apples, Bananas, Carrots, Dates, and Eggplant"),
+			(("Apples", &b, "Carrots", &d, "Eggplant", &f).oxford_and(), "Apples, Bananas, Carrots, Dates, Eggplant, and Figs"),
+		] {
+			assert_eq!(set, expected, "Tuple mismatch.");
+		}
+
+		// The other trait methods should work too.
+		let set = ("Apples", b, "Carrots");
+		assert_eq!(set.oxford_count(), 3);
+		assert_eq!(set.oxford_borrowed(), None);
+		assert_eq!(set.oxford_join_styled(JoinStyle::SEMICOLON_AND), "Apples; Bananas; and Carrots");
+	}
+
+	#[test]
+	fn t_join_counted() {
+		for (set, expected) in [
+			(&[][..], ""),
+			(&["a"][..], "a"),
+			(&["a", "a"][..], "a (×2)"),
+			(&["a", "a", "b"][..], "a (×2) and b"),
+			(&["a", "b", "a"][..], "a (×2) and b"),
+			(&["b", "a", "c", "a", "b", "a"][..], "b (×2), a (×3), and c"),
+			(&["a", "A", "a "][..], "a, A, and a "),
+		] {
+			assert_eq!(set.oxford_join_counted(Conjunction::And), expected);
+			assert_eq!(set.to_vec().oxford_join_counted(Conjunction::And), expected);
+		}
+
+		// Sets without duplicates are joined normally, and borrowed if small.
+		assert!(matches!(["a"].oxford_join_counted(Conjunction::And), Cow::Borrowed("a")));
+		for c in CTEST {
+			let set = ["a", "b", "c"];
+			assert_eq!(set.oxford_join_counted(c), set.oxford_join(c));
+		}
+
+		// Custom markers.
+		assert_eq!(
+			["a", "b", "b"].oxford_join_counted_with(Conjunction::Or, &|n| alloc::format!(" [{n}]")),
+			"a or b [2]",
+		);
+
+		// Callbacks can capture.
+		let unit = "times";
+		assert_eq!(
+			["a", "a", "a", "b"].oxford_join_counted_with(Conjunction::And, &|n| alloc::format!(" {n} {unit}")),
+			"a 3 times and b",
+		);
+	}
+
+	#[test]
+	fn t_join_huge_other() {
+		// A pathologically long custom conjunction shouldn't trip up the
+		// capacity math.
+		let word = "x".repeat(1 << 20);
+		let glue = Conjunction::Other(&word);
+		assert_eq!(glue.glue_len(usize::MAX), usize::MAX);
+		assert_eq!(glue.estimate_len(usize::MAX, 2), usize::MAX);
+
+		for set in [
+			&["a", "b"][..],
+			&["a", "b", "c"],
+			&["a", "b", "c", "d", "e", "f"],
+		] {
+			let expected = glue.oxford_join(set.iter());
+			assert!(expected.contains(word.as_str()));
+
+			let out = set.oxford_join(glue);
+			assert_eq!(out, expected);
+			let Cow::Owned(out) = out else { panic!("Expected owned."); };
+			assert_eq!(out.len(), out.capacity());
+
+			// Too big for a small buffer, but not a panic.
+			let mut buf = [0_u8; 64];
+			let err = oxford_join_slice(set, glue, &mut buf).unwrap_err();
+			assert_eq!(err.needed(), expected.len());
+		}
+	}
+
+	#[test]
+	fn t_join_minimal_impl() {
+		// Downstream impls need the join, count, and walk; everything else
+		// should follow from those.
+		struct Three;
+		impl OxfordJoin for Three {
+			fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+				Cow::Owned(glue.oxford_join(["a", "b", "c"].iter()))
+			}
+			fn oxford_count(&self) -> usize { 3 }
+			fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+				for s in ["a", "b", "c"] { cb(s); }
+			}
+		}
+
+		let set = ["a", "b", "c"];
+		assert_eq!(Three.oxford_and(), "a, b, and c");
+		assert_eq!(Three.oxford_borrowed(), None);
+		for c in CTEST {
+			assert_eq!(Three.oxford_join_styled(JoinStyle::new(c)), set.oxford_join(c));
+			assert_eq!(Three.oxford_join_nonempty(c), set.oxford_join(c));
+			assert_eq!(Three.oxford_segments(c), set.oxford_segments(c));
+			assert_eq!(
+				Three.oxford_join_truncated(c, 1, TruncStyle::DEFAULT),
+				set.oxford_join_truncated(c, 1, TruncStyle::DEFAULT),
+			);
+		}
+		assert_eq!(Three.oxford_join_truncated(Conjunction::And, 1, TruncStyle::DEFAULT), "a and 2 more");
+		assert_eq!(Three.oxford_join_prefixed(Conjunction::And, "#"), "#a, #b, and #c");
+		#[cfg(feature = "small")]
+		assert_eq!(Three.oxford_join_small(Conjunction::And), "a, b, and c");
+
+		// The trait is still object-safe.
+		let dyn_set: &dyn OxfordJoin = &Three;
+		assert_eq!(dyn_set.oxford_join_boxed(Conjunction::Or).as_ref(), "a, b, or c");
+	}
+
+	#[test]
+	fn t_join_boxed() {
+		let set = ["Apples", "Bananas", "Carrots"];
+		for glue in CTEST {
+			for len in 1..=3 {
+				let expected = set[..len].oxford_join(glue);
+				let boxed = set[..len].oxford_join_boxed(glue);
+				assert_eq!(&*boxed, expected);
+				assert_eq!(boxed.len(), expected.len());
+			}
+		}
+
+		// Empty is empty.
+		let set: [&str; 0] = [];
+		assert!(set.oxford_join_boxed(Conjunction::And).is_empty());
+
+		// Large estimated slices shed their slack too.
+		let set = alloc::vec!["Apples"; ONE_PASS * 2];
+		let boxed = set.oxford_join_boxed(Conjunction::And);
+		assert_eq!(boxed.len(), set.oxford_and().len());
+	}
+
+	#[test]
+	fn t_join_checked() {
+		let set = ["Apples", "Bananas", "Carrots"];
+		for glue in CTEST {
+			for len in 0..=3 {
+				let (out, allocated) = set[..len].oxford_join_checked(glue);
+				assert_eq!(out, set[..len].oxford_join(glue));
+				assert_eq!(allocated, 2 <= len);
+			}
+		}
+
+		// Same goes for the non-slice impls.
+		let set: BTreeSet<&str> = set.into_iter().take(1).collect();
+		assert!(! set.oxford_join_checked(Conjunction::And).1);
+	}
+
+	#[test]
+	fn t_join_ranged() {
+		// Empty and singles.
+		assert_eq!(Conjunction::And.oxford_join_ranged::<u8>(&[], "–"), "");
+		assert_eq!(Conjunction::And.oxford_join_ranged(&[5_u8], "–"), "5");
+
+		// Runs of two stay put.
+		assert_eq!(Conjunction::And.oxford_join_ranged(&[1_u8, 2], "–"), "1 and 2");
+		assert_eq!(Conjunction::Or.oxford_join_ranged(&[1_u8, 2, 4], "–"), "1, 2, or 4");
+
+		// Runs of three or more collapse.
+		assert_eq!(Conjunction::And.oxford_join_ranged(&[1_u8, 2, 3], "–"), "1–3");
+		assert_eq!(
+			Conjunction::And.oxford_join_ranged(&[0_u16, 1, 2, 3, 9, 10, 11], " to "),
+			"0 to 3 and 9 to 11",
+		);
+		assert_eq!(
+			Conjunction::And.oxford_join_ranged(&[1_u64, 2, 3, 4, 5, 7], " through "),
+			"1 through 5 and 7",
+		);
+
+		// Signed, unordered, duplicated, and edge values.
+		assert_eq!(
+			Conjunction::And.oxford_join_ranged(&[-2_i32, -1, 0, 0, 5, 4, 3], "–"),
+			"-2–0, 0, 5, 4, and 3",
+		);
+		assert_eq!(
+			Conjunction::And.oxford_join_ranged(&[i16::MIN, i16::MIN + 1, i16::MIN + 2, i16::MAX], "–"),
+			"-32768–-32766 and 32767",
+		);
+		assert_eq!(
+			Conjunction::And.oxford_join_ranged(&[u8::MAX - 2, u8::MAX - 1, u8::MAX, 0], "–"),
+			"253–255 and 0",
+		);
+	}
+
+	#[test]
+	fn t_join_affixed() {
+		const SET: [&str; 4] = ["3", "5", "2", "8"];
+		for c in CTEST {
+			for len in 0..=SET.len() {
+				let set = &SET[..len];
+				let units: Vec<String> = set.iter().map(|s| [s, " kg"].concat()).collect();
+				let money: Vec<String> = set.iter().map(|s| ["$", s].concat()).collect();
+
+				let out = set.oxford_join_suffixed(c, " kg");
+				assert_eq!(out, units.oxford_join(c));
+				if let Cow::Owned(out) = out { assert_eq!(out.capacity(), out.len()); }
+
+				let out = set.oxford_join_prefixed(c, "$");
+				assert_eq!(out, money.oxford_join(c));
+				if let Cow::Owned(out) = out { assert_eq!(out.capacity(), out.len()); }
+
+				// Empty affixes are no affixes.
+				assert_eq!(set.oxford_join_suffixed(c, ""), set.oxford_join(c));
+			}
+		}
+
+		// Dynamic glue works on the bare item.
+		let glue = Conjunction::Dynamic(|s| if s.starts_with('i') { "e" } else { "y" });
+		assert_eq!(
+			["agua", "isla"].oxford_join_prefixed(glue, "la "),
+			"la agua e la isla",
+		);
+	}
+
+	#[test]
+	fn t_styled_both() {
+		const SET: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		let style = JoinStyle::new(Conjunction::And).with_correlative();
+
+		// Only two-item sets get the "both".
+		assert_eq!(SET[..2].oxford_join_styled(style), "both Apples and Bananas");
+		assert_eq!(
+			SET[..2].oxford_join_styled(style).into_owned().capacity(),
+			"both Apples and Bananas".len(),
+		);
+		assert_eq!(SET[..1].oxford_join_styled(style), "Apples");
+		assert_eq!(SET.oxford_join_styled(style), "Apples, Bananas, and Carrots");
+
+		// It's opt-in.
+		assert_eq!(SET[..2].oxford_join_styled(JoinStyle::new(Conjunction::And)), "Apples and Bananas");
+
+		// And only for "and".
+		for c in [Conjunction::Ampersand, Conjunction::Plus, Conjunction::Other("and")] {
+			let style = JoinStyle::new(c).with_correlative();
+			assert_eq!(SET[..2].oxford_join_styled(style), SET[..2].oxford_join(c));
+		}
+	}
+
+	#[test]
+	fn t_styled_last_sep() {
+		const ARR3: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		const ARR4: [&str; 4] = ["Apples", "Bananas", "Carrots", "Dates"];
+
+		// Last separators.
+		let style = JoinStyle::new(Conjunction::Or).with_last_separator(" — ");
+		assert_eq!(style.last_separator(), " — ");
+		assert_eq!(JoinStyle::SEMICOLON_AND.last_separator(), "; ");
+		for (set, expected) in [
+			(&ARR3[..1], "Apples"),
+			(&ARR3[..2], "Apples or Bananas"),
+			(&ARR3[..], "Apples, Bananas — or Carrots"),
+			(&ARR4[..], "Apples, Bananas, Carrots — or Dates"),
+		] {
+			let out = set.oxford_join_styled(style);
+			assert_eq!(out, expected);
+			if let Cow::Owned(out) = out { assert!(out.len() <= out.capacity()); }
+			assert_eq!(set.oxford_join_custom(", ", " — ", Conjunction::Or), expected);
+		}
+		assert_eq!(
+			ARR4.oxford_join_custom("; ", ", ", Conjunction::And),
+			"Apples; Bananas; Carrots, and Dates",
+		);
+
+		// Final separators.
+		for c in CTEST {
+			for set in [&ARR3[..0], &ARR3[..1], &ARR3[..2]] {
+				assert_eq!(set.oxford_join_final_sep(c, "—"), set.oxford_join(c));
+			}
+		}
+		for (set, glue, expected) in [
+			(&ARR3[..], Conjunction::default(), "Apples, Bananas — and Carrots"),
+			(&ARR4[..], Conjunction::default(), "Apples, Bananas, Carrots — and Dates"),
+			(&ARR4[..], Conjunction::Or, "Apples, Bananas, Carrots — or Dates"),
+		] {
+			let out = set.oxford_join_final_sep(glue, "—");
+			assert_eq!(out, expected);
+			assert_eq!(out.into_owned().capacity(), expected.len());
+		}
+	}
+
+	#[test]
+	fn t_styled() {
+		const ARR3: [&str; 3] = ["Apples", "Bananas", "Carrots"];
+		const ARR4: [&str; 4] = ["Apples", "Bananas", "Carrots", "Dates"];
+
+		// The default style should match a regular join.
+		for c in CTEST {
+			let style = JoinStyle::new(c);
+			for set in [&ARR3[..1], &ARR3[..2], &ARR3[..], &ARR4[..]] {
+				assert_eq!(
+					set.oxford_join_styled(style),
+					set.oxford_join(c),
+					"Default style mismatch.",
+				);
+			}
+		}
+
+		// Semicolons!
+		let style = JoinStyle::SEMICOLON_AND;
+		assert_eq!(ARR3.oxford_join_styled(style), "Apples; Bananas; and Carrots");
+		assert_eq!(ARR4.oxford_join_styled(style), "Apples; Bananas; Carrots; and Dates");
+		assert_eq!(
+			BTreeSet::from(ARR4).oxford_join_styled(style),
+			"Apples; Bananas; Carrots; and Dates",
+		);
+		assert_eq!(
+			ARR3.oxford_join_styled(style.with_separator(" | ")),
+			"Apples | Bananas | and Carrots",
+		);
+
+		// Pairs don't use the separator.
+		assert_eq!(ARR3[..2].oxford_join_styled(style), "Apples and Bananas");
+
+		// Correlatives.
+		for c in CTEST {
+			let style = JoinStyle::new(c).with_correlative();
+			let pair = ARR3[..2].oxford_join_styled(style);
+			if let Some(p) = c.correlative() {
+				assert_eq!(pair, [p, " ", &ARR3[..2].oxford_join(c)].concat());
+			}
+			else { assert_eq!(pair, ARR3[..2].oxford_join(c)); }
+
+			// No change for other sizes.
+			assert_eq!(ARR3[..1].oxford_join_styled(style), "Apples");
+			assert_eq!(ARR3.oxford_join_styled(style), ARR3.oxford_join(c));
+		}
+		assert_eq!(
+			ARR3[..2].oxford_join_styled(JoinStyle::new(Conjunction::Nor).with_correlative()),
+			"neither Apples nor Bananas",
+		);
+		let (out, spans) = ["A", "B"].oxford_join_spans(Conjunction::Or);
+		assert_eq!(out, "A or B");
+		assert_eq!(spans, [0..1, 5..6]);
+
+		// Or both.
+		for c in CTEST {
+			let style = JoinStyle::new(c).with_or_both();
+			let pair = ARR3[..2].oxford_join_styled(style);
+			if c == Conjunction::AndOr {
+				assert_eq!(pair, "Apples, Bananas, or both");
+				assert_eq!(pair.into_owned().capacity(), 24, "Or-both capacity mismatch.");
+			}
+			else { assert_eq!(pair, ARR3[..2].oxford_join(c)); }
+			assert_eq!(ARR3[..1].oxford_join_styled(style), "Apples");
+			assert_eq!(ARR3.oxford_join_styled(style), ARR3.oxford_join(c));
+		}
+		assert_eq!(
+			ARR3[..2].oxford_join_styled(JoinStyle::new(Conjunction::AndOr).with_or_both().with_separator("; ")),
+			"Apples; Bananas; or both",
+		);
+
+		// Pair separators.
+		for c in CTEST {
+			let style = JoinStyle::new(c)
+				.with_pair_separator(" — ")
+				.with_correlative()
+				.with_or_both()
+				.with_tight();
+			assert_eq!(ARR3[..2].oxford_join_styled(style), "Apples — Bananas");
+			assert_eq!(ARR3[..1].oxford_join_styled(style), "Apples");
+			assert_eq!(
+				ARR4.oxford_join_styled(style.with_separator("; ")),
+				ARR4.oxford_join_styled(JoinStyle::new(c).with_tight().with_separator("; ")),
+			);
+		}
+		assert_eq!(
+			ARR3[..2].oxford_join_styled(JoinStyle::new(Conjunction::And).with_pair_separator("/")),
+			"Apples/Bananas",
+		);
+	}
+
+	#[test]
+	fn t_tight() {
+		const SET: [&str; 4] = ["A", "B", "C", "D"];
+		for (len, amp, plus) in [
+			(0, "", ""),
+			(1, "A", "A"),
+			(2, "A&B", "A+B"),
+			(3, "A&B&C", "A+B+C"),
+			(4, "A&B&C&D", "A+B+C+D"),
+		] {
+			let set = &SET[..len];
+
+			// Tight.
+			let style = JoinStyle::new(Conjunction::Ampersand).with_tight();
+			assert_eq!(set.oxford_join_styled(style), amp);
+			let style = JoinStyle::new(Conjunction::Plus).with_tight();
+			assert_eq!(set.oxford_join_styled(style), plus);
+
+			// Loose.
+			for c in [Conjunction::Ampersand, Conjunction::Plus] {
+				assert_eq!(set.oxford_join_styled(JoinStyle::new(c)), set.oxford_join(c));
+			}
+
+			// Words ignore tightness.
+			let style = JoinStyle::new(Conjunction::And).with_tight();
+			assert_eq!(set.oxford_join_styled(style), set.oxford_join(Conjunction::And));
+		}
+
+		// Spans should line up.
+		let style = JoinStyle::new(Conjunction::Plus).with_tight();
+		let mut out = String::new();
+		let mut spans = Vec::new();
+		write_styled(["AB", "C", "DE"].as_slice(), style, &mut out, 0, |r| spans.push(r));
+		assert_eq!(out, "AB+C+DE");
+		assert_eq!(spans, [0..2, 3..4, 5..7]);
+	}
+
+	#[test]
+	fn t_spans() {
+		const ARR4: [&str; 4] = ["Apples", "Bananas", "Carrots", "Apples"];
+
+		for c in CTEST {
+			for len in 0..=ARR4.len() {
+				let set = &ARR4[..len];
+				let (out, spans) = set.oxford_join_spans(c);
+				assert_eq!(out, set.oxford_join(c), "Spanned join mismatch.");
+				assert_eq!(spans.len(), len, "Wrong number of spans.");
+				for (r, v) in spans.into_iter().zip(set) {
+					assert_eq!(&out[r], *v, "Span mismatch.");
+				}
+			}
+		}
+
+		// Multi-byte conjunctions.
+		let (out, spans) = ARR4.oxford_join_spans(Conjunction::Other("και"));
+		assert_eq!(out, "Apples, Bananas, Carrots, και Apples");
+		assert_eq!(&out[spans[3].clone()], "Apples");
+		assert_eq!(spans[0], 0..6);
+		assert_eq!(spans[3], 33..39);
+	}
+
+	#[test]
+	fn t_spanned() {
+		const ARR4: [&str; 4] = ["Apples", "Bananas", "Carrots", "Apples"];
+
+		for c in CTEST {
+			for len in 0..=ARR4.len() {
+				let set = &ARR4[..len];
+				let (out, span) = set.oxford_join_spanned(c);
+				assert_eq!(out, set.oxford_join(c), "Spanned join mismatch.");
+				assert_eq!(&out[span.clone()], set.last().copied().unwrap_or_default());
+				if len != 0 { assert_eq!(span.end, out.len(), "Span should end the string."); }
+			}
+		}
+
+		// Empty is empty.
+		let (out, span) = ARR4[..0].oxford_join_spanned(Conjunction::And);
+		assert!(out.is_empty());
+		assert_eq!(span, 0..0);
+	}
+
+	#[test]
+	fn t_split() {
+		cross_check(|set, c| {
+			let (head, last) = set.oxford_split(c);
+			assert_eq!(last, set.last().copied(), "Wrong last.");
+			[head.as_ref(), last.unwrap_or_default()].concat()
+		});
+
+		// Commas in the items don't matter.
+		let (head, last) = ["Paris, France", "Rome, Italy"].oxford_split(Conjunction::Or);
+		assert_eq!(head, "Paris, France or ");
+		assert_eq!(last, Some("Rome, Italy"));
+	}
+
+	#[test]
+	fn t_sentence_case() {
+		for (set, expected) in [
+			(&[][..], ""),
+			(&[""][..], ""),
+			(&["", "b"][..], " and b"),
+			(&["apples"][..], "Apples"),
+			(&["Apples"][..], "Apples"),
+			(&["1 apple", "2 bananas"][..], "1 apple and 2 bananas"),
+			(&["apples", "bananas", "carrots"][..], "Apples, bananas, and carrots"),
+			(&["élan", "ennui"][..], "Élan and ennui"),
+			(&["ŉ", "b"][..], "ʼN and b"),
+			(&["ßeta", "b", "c"][..], "SSeta, b, and c"),
+			(&["ﬀ", "b"][..], "FF and b"),
+		] {
+			let out = set.oxford_join_sentence_case(Conjunction::And);
+			assert_eq!(out, expected);
+
+			// Conversions should not require reallocation, which would
+			// (roughly) double the capacity. (Shrinking conversions leave a
+			// little slack, and tiny allocations are rounded up to eight.)
+			if let Cow::Owned(out) = out {
+				assert!(out.capacity() <= (out.len() + 4).max(8), "Capacity mismatch for {out}.");
+			}
+		}
+
+		// Borrow when possible.
+		assert!(matches!(["Apples"].oxford_join_sentence_case(Conjunction::And), Cow::Borrowed(_)));
+	}
+
+	#[test]
+	fn t_clamped() {
+		const SET: [&str; 3] = ["Äpfel", "Birnen", "Kirschen"];
+		let full = SET.oxford_and();
+		assert_eq!(full, "Äpfel, Birnen, and Kirschen");
+		assert_eq!(full.len(), 28);
+
+		// Everything fits.
+		for max in [28, 29, 100] {
+			assert_eq!(SET.oxford_join_clamped(Conjunction::And, max), full);
+		}
+
+		// Progressively shorter.
+		for max in 0..28 {
+			let out = SET.oxford_join_clamped(Conjunction::And, max);
+			assert!(out.len() <= max, "Clamped output too long.");
+			if max < 3 { assert!(out.is_empty(), "Expected empty output."); }
+			else {
+				assert!(out.ends_with('…'), "Missing ellipsis.");
+				assert!(full.starts_with(out.trim_end_matches('…')), "Clamped output mismatch.");
+			}
+		}
+
+		// Multi-byte characters can't be split.
+		assert_eq!(SET.oxford_join_clamped(Conjunction::And, 4), "…");
+		assert_eq!(SET.oxford_join_clamped(Conjunction::And, 5), "Ä…");
+		assert_eq!(SET.oxford_join_clamped(Conjunction::And, 6), "Äp…");
+		assert_eq!(
+			SET.oxford_join_clamped(Conjunction::Other("und"), 20),
+			"Äpfel, Birnen…",
+		);
+
+		// Budgets landing mid-item should drop the item entirely.
+		let set = ["Apples", "Oranges", "Bananas"];
+		for max in 9..18 {
+			assert_eq!(set.oxford_join_clamped(Conjunction::Or, max), "Apples…");
+		}
+		for max in 18..27 {
+			assert_eq!(set.oxford_join_clamped(Conjunction::Or, max), "Apples, Oranges…");
+		}
+		assert_eq!(
+			["Apples", "Oranges"].oxford_join_clamped(Conjunction::Or, 16),
+			"Apples…",
+		);
+
+		// Borrowed output should survive if it fits.
+		assert!(matches!(
+			["Äpfel"].oxford_join_clamped(Conjunction::And, 6),
+			Cow::Borrowed("Äpfel"),
+		));
+	}
+
+	#[test]
+	fn t_clamped_with() {
+		const SET: [&str; 3] = ["Äpfel", "Birnen", "Kirschen"];
+		let full = SET.oxford_and();
+
+		// The default style is the default.
+		for max in 0..=30 {
+			assert_eq!(
+				SET.oxford_join_clamped_with(Conjunction::And, max, TruncStyle::default()),
+				SET.oxford_join_clamped(Conjunction::And, max),
+			);
+		}
+
+		// Custom ellipses count toward the total.
+		let style = TruncStyle::DEFAULT.with_ellipsis(" (etc.)");
+		for max in 0..28 {
+			let out = SET.oxford_join_clamped_with(Conjunction::And, max, style);
+			assert!(out.len() <= max, "Clamped output too long.");
+			if max < 7 { assert!(out.is_empty(), "Expected empty output."); }
+			else {
+				assert!(out.ends_with(" (etc.)"), "Missing ellipsis.");
+				assert!(full.starts_with(out.trim_end_matches(" (etc.)")), "Clamped output mismatch.");
+			}
+		}
+		assert_eq!(SET.oxford_join_clamped_with(Conjunction::And, 20, style), "Äpfel (etc.)");
+
+		// Empty ones just cut.
+		let style = TruncStyle::DEFAULT.with_ellipsis("");
+		assert_eq!(SET.oxford_join_clamped_with(Conjunction::And, 14, style), "Äpfel, Birnen");
+		assert_eq!(SET.oxford_join_clamped_with(Conjunction::And, 13, style), "Äpfel");
+		assert_eq!(SET.oxford_join_clamped_with(Conjunction::And, 4, style), "Äpf");
+		assert_eq!(SET.oxford_join_clamped_with(Conjunction::And, 28, style), full);
+	}
+
+	#[test]
+	fn t_truncated() {
+		let style = TruncStyle::new("…", "autres");
+		for c in CTEST {
+			for len in 0..=FRUIT.len() {
+				let set = &FRUIT[..len];
+				for max in 0..=FRUIT.len() {
+					let out = set.oxford_join_truncated(c, max, style);
+					if len <= max { assert_eq!(out, set.oxford_join(c)); }
+					else {
+						let more = alloc::format!("{} autres", len - max);
+						let mut expected = set[..max].to_vec();
+						expected.push(&more);
+						assert_eq!(out, expected.oxford_join(c));
+					}
+				}
+			}
+		}
+
+		assert_eq!(
+			FRUIT.oxford_join_truncated(Conjunction::Other("et"), 3, style),
+			"Apples, Bananas, Carrots, et 2 autres",
+		);
+		assert_eq!(FRUIT.oxford_join_truncated(Conjunction::And, 0, style), "5 autres");
+
+		// Empty more words are skipped.
+		assert_eq!(
+			FRUIT.oxford_join_truncated(Conjunction::Plus, 2, style.with_more("")),
+			"Apples, Bananas, + 3",
+		);
+	}
+
+	#[test]
+	fn t_truncated_with() {
+		for c in CTEST {
+			for len in 0..=FRUIT.len() {
+				let set = &FRUIT[..len];
+				for max in 0..=FRUIT.len() {
+					let out = set.oxford_join_truncated_with(c, max, &|n| alloc::format!(" (+{n})"));
+					if len <= max { assert_eq!(out, set.oxford_join(c)); }
+					else {
+						let expected = alloc::format!("{} (+{})", set[..max].join(", "), len - max);
+						assert_eq!(out, expected);
+					}
+				}
+			}
+		}
+
+		// The tail is used verbatim.
+		assert_eq!(
+			FRUIT.oxford_join_truncated_with(Conjunction::And, 0, &|n| alloc::format!("{n} fruits")),
+			"5 fruits",
+		);
+		assert_eq!(
+			FRUIT.oxford_join_truncated_with(Conjunction::And, 4, &|_| String::new()),
+			"Apples, Bananas, Carrots, Dates",
+		);
+
+		// And can capture.
+		let style = TruncStyle::DEFAULT.with_more("autres");
+		assert_eq!(
+			FRUIT.oxford_join_truncated_with(Conjunction::And, 1, &|n| alloc::format!(" +{n} {}", style.more())),
+			"Apples +4 autres",
+		);
+	}
+
+	#[cfg(feature = "unicode-width")]
+	#[test]
+	fn t_clamped_width() {
+		const SET: [&str; 3] = ["日本", "🍎", "x"];
+
+		// The full join is 15 columns, but 19 bytes.
+		let full = SET.oxford_and();
+		assert_eq!(full, "日本, 🍎, and x");
+		assert_eq!(full.len(), 19);
+		for max in [15, 16, 19, 100] {
+			assert_eq!(SET.oxford_join_clamped_width(Conjunction::And, max), full);
+		}
+
+		// Clamped!
+		assert_eq!(SET.oxford_join_clamped_width(Conjunction::And, 14), "日本, 🍎…");
+		assert_eq!(SET.oxford_join_clamped_width(Conjunction::And, 9), "日本, 🍎…");
+		assert_eq!(SET.oxford_join_clamped_width(Conjunction::And, 8), "日本…");
+
+		// Wide characters can't be split, so might leave a gap.
+		assert_eq!(SET.oxford_join_clamped_width(Conjunction::And, 5), "日本…");
+		assert_eq!(SET.oxford_join_clamped_width(Conjunction::And, 4), "日…");
+		assert_eq!(SET.oxford_join_clamped_width(Conjunction::And, 2), "…");
+		assert_eq!(SET.oxford_join_clamped_width(Conjunction::And, 1), "…");
+		assert_eq!(SET.oxford_join_clamped_width(Conjunction::And, 0), "");
+
+		// Custom ellipses are measured too.
+		let style = TruncStyle::DEFAULT.with_ellipsis("⋯⋯");
+		assert_eq!(SET.oxford_join_clamped_width_with(Conjunction::And, 10, style), "日本, 🍎⋯⋯");
+		assert_eq!(SET.oxford_join_clamped_width_with(Conjunction::And, 1, style), "");
+
+		// Zero-width characters should stick with their base.
+		let set = ["e\u{301}e\u{301}e\u{301}"];
+		assert_eq!(set.oxford_join_clamped_width(Conjunction::And, 3), set[0]);
+		assert_eq!(set.oxford_join_clamped_width(Conjunction::And, 2), "e\u{301}…");
+
+		// Borrowed output should survive if it fits.
+		assert!(matches!(
+			["日本"].oxford_join_clamped_width(Conjunction::And, 4),
+			Cow::Borrowed("日本"),
+		));
+	}
+
+	#[cfg(feature = "html")]
+	#[test]
+	fn t_join_html() {
+		assert_eq!(escape_html("Apples"), Cow::Borrowed("Apples"));
+		assert_eq!(escape_html(r##"<a href="#">Tom's</a> & Co"##), "&lt;a href=&quot;#&quot;&gt;Tom&#39;s&lt;/a&gt; &amp; Co");
+		assert_eq!(escape_html("Björk & Sigur Rós"), "Björk &amp; Sigur Rós");
+
+		for (set, expected) in [
+			(&[][..], ""),
+			(&["A&W"][..], "A&amp;W"),
+			(&["A&W", "<b>"][..], "A&amp;W &amp; &lt;b&gt;"),
+			(&["A&W", "<b>", "\"q\""][..], "A&amp;W, &lt;b&gt;, &amp; &quot;q&quot;"),
+		] {
+			assert_eq!(set.oxford_join_html(Conjunction::Ampersand), expected);
+		}
+
+		// Borrowed when possible.
+		assert!(matches!(["Apples"].oxford_join_html(Conjunction::And), Cow::Borrowed(_)));
+
+		// Custom conjunctions are escaped too, and match the plain version
+		// otherwise.
+		for c in CTEST {
+			let set = ["Apples", "Bananas", "Carrots"];
+			assert_eq!(set.oxford_join_html(c), escape_html(&set.oxford_join(c)));
+		}
+		assert_eq!(["a", "b"].oxford_join_html(Conjunction::Other("<&>")), "a &lt;&amp;&gt; b");
+	}
+
+	#[test]
+	fn t_segments() {
+		for (len, expected) in [0, 1, 3, 6, 8, 10].into_iter().enumerate() {
+			let set = &FRUIT[..len];
+			assert_eq!(set.oxford_segments(Conjunction::And), expected, "Segments ({len}).");
+
+			// Empty conjunctions are not counted.
+			let expected = if len < 2 { expected } else { expected - 1 };
+			assert_eq!(set.oxford_segments(Conjunction::Other("")), expected, "Segments ({len}, empty).");
+		}
+
+		// Comma has separators but no conjunction.
+		for (len, expected) in [0, 1, 3, 5, 7, 9].into_iter().enumerate() {
+			let set = &FRUIT[..len];
+			assert_eq!(set.oxford_segments(Conjunction::Comma), expected, "Segments ({len}, comma).");
+
+			// Raw glue replaces the last separator.
+			let glue = Conjunction::OtherRaw { pair: " → ", list: " → " };
+			assert_eq!(set.oxford_segments(glue), expected, "Segments ({len}, raw).");
+		}
+	}
+
+	#[test]
+	fn t_join_progress() {
+		use alloc::vec;
+
+		/// # Grumpy Writer.
+		///
+		/// This errors out once it has more than `n` bytes.
+		struct Grumpy(usize);
+		impl core::fmt::Write for Grumpy {
+			fn write_str(&mut self, s: &str) -> core::fmt::Result {
+				self.0 = self.0.checked_sub(s.len()).ok_or(core::fmt::Error)?;
+				Ok(())
+			}
+		}
+
+		cross_check(|set, c| {
+			let mut out = String::new();
+			let mut seen = Vec::new();
+			assert!(set.oxford_join_progress(c, &mut out, &mut |idx| seen.push(idx)).is_ok());
+			assert_eq!(seen, (0..set.len()).collect::<Vec<_>>(), "Progress mismatch.");
+			out
+		});
+
+		// Errors stop everything.
+		let mut seen = Vec::new();
+		assert!(
+			FRUIT.oxford_join_progress(Conjunction::And, &mut Grumpy(20), &mut |idx| seen.push(idx))
+				.is_err()
+		);
+		assert_eq!(seen, vec![0, 1]);
+	}
+
+	#[test]
+	fn t_join_trimmed() {
+		for (set, expected) in [
+			(&[][..], ""),
+			(&[" a "][..], "a"),
+			(&["", "a"][..], "a"),
+			(&[" a ", "b "][..], "a and b"),
+			(&["  a", " ", "b\t", "\nc"][..], "a, b, and c"),
+			(&["", " ", "\t"][..], ""),
+			(&["a", "b", "c"][..], "a, b, and c"),
+		] {
+			let out = set.oxford_join_trimmed(Conjunction::And);
+			assert_eq!(out, expected);
+
+			// Capacity should be sufficient.
+			if let Cow::Owned(out) = out { assert!(out.len() <= out.capacity()); }
+		}
+
+		// Single survivors should be borrowed.
+		assert!(matches!(["", " a "].oxford_join_trimmed(Conjunction::And), Cow::Borrowed("a")));
+
+		// Dynamic glue should see the trimmed value.
+		let glue = Conjunction::Dynamic(|s| if s.starts_with('I') { "e" } else { "y" });
+		assert_eq!(["Juan ", " Inés"].oxford_join_trimmed(glue), "Juan e Inés");
+	}
+
+	#[test]
+	fn t_join_nonempty() {
+		for (set, expected) in [
+			(&[][..], ""),
+			(&["", ""][..], ""),
+			(&["", "Apples", ""][..], "Apples"),
+			(&["a", "", "b"][..], "a and b"),
+			(&["", "a", "", "b", ""][..], "a and b"),
+			(&[" Apples ", "", "Bananas"][..], " Apples  and Bananas"),
+			(&["Apples", "Bananas", "Carrots"][..], "Apples, Bananas, and Carrots"),
+			(&["", "Apples", "", "Bananas", "", "Carrots", ""][..], "Apples, Bananas, and Carrots"),
+		] {
+			assert_eq!(set.oxford_join_nonempty(Conjunction::And), expected);
+		}
+
+		// Whitespace isn't empty.
+		for (set, expected) in [
+			(&[" "][..], " "),
+			(&["", " \t"][..], " \t"),
+			(&["", "Apples", " "][..], "Apples and  "),
+			(&["Apples", "\n", "Bananas"][..], "Apples, \n, and Bananas"),
+		] {
+			assert_eq!(set.oxford_join_nonempty(Conjunction::And), expected);
+		}
+
+		// Borrowing is possible when there's only one non-empty item.
+		assert!(matches!(["", "Apples"].oxford_join_nonempty(Conjunction::And), Cow::Borrowed(_)));
+
+		// Without empties, it should match a regular join.
+		let set = ["Apples", "Bananas", "Carrots", "Dates"];
+		for c in CTEST {
+			assert_eq!(set.oxford_join_nonempty(c), set.oxford_join(c));
+		}
+	}
+
+	#[test]
+	fn t_join_repeated() {
+		/// # Spanish "And".
+		fn y(next: &str) -> &'static str {
+			if next.starts_with('i') { "e" } else { "y" }
+		}
+
+		const SET: [&str; 4] = ["a", "b", "c", "d"];
+		for (len, expected) in [
+			(0, ""),
+			(1, "a"),
+			(2, "a and b"),
+			(3, "a and b and c"),
+			(4, "a and b and c and d"),
+		] {
+			let set = &SET[..len];
+			assert_eq!(set.oxford_join_repeated(Conjunction::And), expected);
+
+			// It should match JoinFmt with the right glue.
+			for c in CTEST {
+				let glue = c.pair_parts().concat();
+				assert_eq!(
+					set.oxford_join_repeated(c),
+					alloc::format!("{}", JoinFmt::new(set.iter(), &glue)),
+				);
+			}
+		}
+
+		// Dynamic conjunctions get resolved for each item.
+		assert_eq!(
+			["Juan", "isla", "mar", "iglesia"].oxford_join_repeated(Conjunction::Dynamic(y)),
+			"Juan e isla y mar e iglesia",
+		);
+	}
+
+	#[test]
+	fn t_join_etc() {
+		const SET: [&str; 4] = ["Apples", "Bananas", "Carrots", "Dates"];
+		for (len, expected) in [
+			(0, ""),
+			(1, "Apples, etc."),
+			(2, "Apples, Bananas, etc."),
+			(3, "Apples, Bananas, Carrots, etc."),
+			(4, "Apples, Bananas, Carrots, Dates, etc."),
+		] {
+			let out = SET[..len].oxford_join_etc("etc.").into_owned();
+			assert_eq!(out, expected);
+			assert_eq!(out.len(), out.capacity(), "Capacity mismatch.");
+		}
+
+		assert_eq!(
+			BTreeSet::from(SET).oxford_join_etc("and so on"),
+			"Apples, Bananas, Carrots, Dates, and so on",
+		);
+	}
+
+	#[test]
+	fn t_join_map() {
+		use alloc::string::ToString;
+
+		let set = ["Apples", "bananas", "CARROTS"];
+		assert_eq!(
+			Conjunction::And.oxford_join_map(set, |s| Cow::Owned(s.to_uppercase())),
+			"APPLES, BANANAS, and CARROTS",
+		);
+		assert_eq!(
+			Conjunction::Or.oxford_join_map(&set[..2], |s| Cow::Owned(s.to_uppercase())),
+			"APPLES or BANANAS",
+		);
+
+		// Borrowing should work too.
+		assert_eq!(
+			Conjunction::And.oxford_join_map(set, Cow::Borrowed),
+			Conjunction::And.oxford_join(set),
+		);
+
+		// And owned sources.
+		assert_eq!(
+			Conjunction::And.oxford_join_map(
+				set.iter().map(ToString::to_string),
+				|s| Cow::Owned(s.to_uppercase()),
+			),
+			"APPLES, BANANAS, and CARROTS",
+		);
+	}
+
+	#[test]
+	fn t_join_into() {
+		const SET: [&str; 4] = ["Apples", "Bananas", "Carrots", "Dates"];
+
+		let mut buf = String::from("Leftovers!");
+		for c in CTEST {
+			for len in 0..=SET.len() {
+				c.oxford_join_into(&SET[..len], &mut buf);
+				assert_eq!(buf, SET[..len].oxford_join(c), "Join-into mismatch.");
+			}
+		}
+	}
+
+	#[test]
+	fn t_join_append() {
+		const SET: [&str; 4] = ["Apples", "Bananas", "Carrots", "Dates"];
+
+		for c in CTEST {
+			let mut buf = String::from("Leftovers!");
+			let mut expected = buf.clone();
+			for len in 0..=SET.len() {
+				c.oxford_join_append(SET[..len].iter(), &mut buf);
+				expected.push_str(&SET[..len].oxford_join(c));
+				assert_eq!(buf, expected, "Join-append mismatch.");
+			}
+		}
+
+		// Empty sets write nothing.
+		let mut buf = String::from("Hello");
+		Conjunction::And.oxford_join_append(core::iter::empty::<&str>(), &mut buf);
+		assert_eq!(buf, "Hello");
+	}
+
+	#[test]
+	fn t_join_chars() {
+		for c in CTEST {
+			for s in ["", "a", "ab", "abc", "hello", "日本語", "🍎🍌"] {
+				assert_eq!(
+					c.oxford_join_chars(s.chars()),
+					c.oxford_join(s.chars().map(String::from)),
+				);
+			}
+		}
+		assert_eq!(Conjunction::And.oxford_join_chars("日本語".chars()), "日, 本, and 語");
+	}
+
+	#[test]
+	fn t_join_large() {
+		use alloc::string::ToString;
+
+		// Large items should be joined with a single allocation.
+		let set: Vec<String> = (b'A'..=b'J')
+			.map(|b| char::from(b).to_string().repeat(1024))
+			.collect();
+		let expected = set.iter().map(String::len).sum::<usize>() + 9 * 2 + 4;
+
+		let out = set.oxford_and();
+		assert_eq!(out.len(), expected);
+		assert_eq!(out.into_owned().capacity(), expected, "Slice reallocated.");
 
-	#[inline]
-	/// # Oxford Join (or).
-	///
-	/// This is equivalent to calling `oxford_join(Conjunction::Or)`.
-	///
-	/// ## Examples
-	///
-	/// ```
-	/// use oxford_join::{Conjunction, OxfordJoin};
-	///
-	/// let set = ["Apples", "Oranges"];
-	/// assert_eq!(set.oxford_join(Conjunction::Or), set.oxford_or());
-	/// ```
-	fn oxford_or(&self) -> Cow<str> { self.oxford_join(Conjunction::Or) }
-}
+		// The generic version can only estimate, but shouldn't need to
+		// reallocate when the items are all the same size.
+		let out = Conjunction::And.oxford_join(&set);
+		assert_eq!(out, set.oxford_and());
+		assert!(
+			(expected..expected + 64).contains(&out.capacity()),
+			"Generic reallocated.",
+		);
+	}
 
-impl<T> OxfordJoin for [T] where T: AsRef<str> {
-	#[expect(unsafe_code, reason = "Strings in, strings out.")]
-	/// # Oxford Join.
-	fn oxford_join(&self, glue: Conjunction) -> Cow<str> {
-		// 2+ elements.
-		if let [first, mid @ .., last] = self {
-			let first = first.as_ref().as_bytes();
-			let last = last.as_ref().as_bytes();
-
-			// 2 elements.
-			if mid.is_empty() {
-				let len = first.len() + last.len() + 2 + glue.len();
-				let mut v = Vec::with_capacity(len);
-				v.extend_from_slice(first); // First.
-				glue.append_two(&mut v);    // Conjunction.
-				v.extend_from_slice(last);  // Last.
-
-				// Safety: strings in, strings out.
-				let out = unsafe { String::from_utf8_unchecked(v) };
-				Cow::Owned(out)
+	#[test]
+	fn t_join_one_pass() {
+		use alloc::string::ToString;
+
+		// Either side of the one-pass threshold, with uneven item lengths.
+		// (The threshold counts middle items, so add two for the ends.)
+		for len in [ONE_PASS + 1, ONE_PASS + 2, ONE_PASS + 3, 1000] {
+			let set: Vec<String> = (0..len).map(|n| "x".repeat(n % 7) + &n.to_string()).collect();
+			let expected = Conjunction::And.oxford_join(set.iter().map(String::as_str));
+			let out = set.oxford_and().into_owned();
+			assert_eq!(out, expected, "Mismatch at {len}.");
+			assert!(out.len() <= out.capacity(), "Capacity mismatch at {len}.");
+			if len < ONE_PASS + 2 {
+				assert_eq!(out.len(), out.capacity(), "Inexact capacity at {len}.");
 			}
-			// 3+ elements.
-			else {
-				let len =
-					glue.len() + 1 +                                     // Glue length plus one trailing space.
-					((mid.len() + 1) * 2) +                              // Commaspace (2) for all but last entry.
-					first.len() + last.len() +                           // First and last item length.
-					mid.iter().map(|x| x.as_ref().len()).sum::<usize>(); // All other item lengths.
-				let mut v = Vec::with_capacity(len);
-
-				// Write the first.
-				v.extend_from_slice(first);
-
-				// Write the middles.
-				for s in mid {
-					v.extend_from_slice(COMMASPACE);
-					v.extend_from_slice(s.as_ref().as_bytes());
+		}
+
+		// Lopsided sets shouldn't be grossly over-allocated.
+		let mut set = alloc::vec![""; 1000];
+		let big = "x".repeat(5000);
+		set[0] = &big;
+		set[999] = &big;
+		let out = set.oxford_and().into_owned();
+		assert!(out.capacity() <= out.len() * 2, "Over-allocated.");
+	}
+
+	#[test]
+	fn t_join_capacity_sweep() {
+		/// # Pseudo-Random Numbers (Xorshift).
+		fn next(state: &mut u32) -> usize {
+			*state ^= *state << 13;
+			*state ^= *state >> 17;
+			*state ^= *state << 5;
+			*state as usize
+		}
+
+		/// # Check It.
+		fn check<S: OxfordJoin + ?Sized>(set: &S, glue: Conjunction, expected: &str) {
+			let out = set.oxford_join(glue);
+			assert_eq!(out, expected);
+			if let Cow::Owned(out) = out { assert_eq!(out.len(), out.capacity()); }
+		}
+
+		// A mix of empty, ASCII, and multi-byte pieces.
+		const POOL: [&str; 8] = ["", "a", "Äpfel", "日本", "🍎🍎", " ", "και", "x,y"];
+		let glues = CTEST.into_iter().chain([
+			Conjunction::Other("και"),
+			Conjunction::Other(""),
+			Conjunction::OtherRaw { pair: "", list: " ⇒ " },
+			Conjunction::Dynamic(|s| if s.len() % 2 == 0 { "y" } else { "e" }),
+		]);
+
+		let mut state = 0x9e37_79b9_u32;
+		for glue in glues {
+			for _ in 0..200 {
+				let len = next(&mut state) % 12;
+				let set: Vec<String> = (0..len)
+					.map(|_| (0..next(&mut state) % 4).map(|_| POOL[next(&mut state) % POOL.len()]).collect())
+					.collect();
+				let expected = glue.oxford_join(set.iter());
+
+				check(set.as_slice(), glue, &expected);
+				check(&set.iter().map(String::as_str).collect::<VecDeque<_>>(), glue, &expected);
+				check(&set.iter().map(String::as_str).collect::<LinkedList<_>>(), glue, &expected);
+				macro_rules! arrays {
+					($($num:literal),+) => ($(
+						if let Ok(arr) = <[&str; $num]>::try_from(set.iter().map(String::as_str).collect::<Vec<_>>()) {
+							check(&arr, glue, &expected);
+						}
+					)+);
 				}
+				arrays!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
 
-				// Write the conjunction and last.
-				glue.append_to(&mut v);
-				v.extend_from_slice(last);
+				// Styled joins reserve rather than preallocate, so the
+				// allocator may round tiny outputs up.
+				let style = JoinStyle::new(glue).with_separator("; ");
+				if let Cow::Owned(out) = set.oxford_join_styled(style) {
+					assert!(out.len() <= out.capacity(), "Styled capacity.");
+				}
+			}
+		}
+	}
 
-				// Safety: strings in, strings out.
-				let out = unsafe { String::from_utf8_unchecked(v) };
-				Cow::Owned(out)
+	#[test]
+	fn t_cow() {
+		/// # Generic Join.
+		fn join<S: OxfordJoin + ?Sized>(set: &S) -> String { set.oxford_and().into_owned() }
+
+		/// # Normalize.
+		fn lower(s: &str) -> Cow<'_, str> {
+			if s.bytes().any(|b| b.is_ascii_uppercase()) { Cow::Owned(s.to_ascii_lowercase()) }
+			else { Cow::Borrowed(s) }
+		}
+
+		const RAW: [&str; 3] = ["apples", "BANANAS", "carrots"];
+		const EXPECTED: &str = "apples, bananas, and carrots";
+
+		let set: Vec<Cow<str>> = RAW.iter().map(|s| lower(s)).collect();
+		assert!(matches!(set[0], Cow::Borrowed(_)));
+		assert!(matches!(set[1], Cow::Owned(_)));
+
+		// Collections.
+		assert_eq!(set.oxford_and(), EXPECTED);
+		assert_eq!(set.as_slice().oxford_and(), EXPECTED);
+		assert_eq!(set.clone().into_boxed_slice().oxford_and(), EXPECTED);
+		assert_eq!(set.iter().cloned().collect::<VecDeque<_>>().oxford_and(), EXPECTED);
+		assert_eq!(set.iter().cloned().collect::<BTreeSet<_>>().oxford_and(), EXPECTED);
+		assert_eq!(join(&set), EXPECTED);
+		assert_eq!(join(&[lower("A"), lower("b")]), "a and b");
+
+		// Iterators, by value and by reference.
+		assert_eq!(Conjunction::And.oxford_join(set.iter()), EXPECTED);
+		assert_eq!(Conjunction::And.oxford_join(set.clone()), EXPECTED);
+		assert_eq!(Conjunction::And.oxford_join(RAW.iter().map(|s| lower(s))), EXPECTED);
+		assert_eq!(Conjunction::And.oxford_join_map(RAW, lower), EXPECTED);
+
+		// Single owned items are borrowed from the set.
+		let one = [lower("APPLES")];
+		assert!(matches!(one.oxford_and(), Cow::Borrowed("apples")));
+	}
+
+	#[test]
+	fn t_try_oxford_join() {
+		let set: [Result<&str, u8>; 3] = [Ok("Apples"), Ok("Bananas"), Ok("Carrots")];
+		for c in CTEST {
+			for len in 0..=set.len() {
+				assert_eq!(
+					c.try_oxford_join(set[..len].iter().copied()),
+					Ok(c.oxford_join(["Apples", "Bananas", "Carrots"][..len].iter())),
+				);
 			}
 		}
-		// One element.
-		else if self.len() == 1 { Cow::Borrowed(self[0].as_ref()) }
-		// No elements.
-		else { Cow::Borrowed("") }
+
+		// The first error wins, and nothing after it is pulled.
+		let mut pulled = 0;
+		let iter = [Ok("Apples"), Err(1_u8), Ok("Bananas"), Err(2)].into_iter()
+			.inspect(|_| pulled += 1);
+		assert_eq!(Conjunction::And.try_oxford_join(iter), Err(1));
+		assert_eq!(pulled, 2);
 	}
-}
 
-impl<T> OxfordJoin for [T; 0] where T: AsRef<str> {
-	#[inline]
-	/// # Oxford Join.
-	///
-	/// This is a special case; the result is always empty.
-	fn oxford_join(&self, _glue: Conjunction) -> Cow<str> { Cow::Borrowed("") }
-}
+	#[test]
+	fn t_join_repeat() {
+		for (n, expected) in [
+			(0, ""),
+			(1, "a"),
+			(2, "a or a"),
+			(5, "a, a, a, a, or a"),
+		] {
+			assert_eq!(Conjunction::Or.oxford_join_repeat("a", n), expected);
+		}
+	}
 
-impl<T> OxfordJoin for [T; 1] where T: AsRef<str> {
-	#[inline]
-	/// # Oxford Join.
-	///
-	/// This is a special case; the sole entry will be returned as-is.
-	fn oxford_join(&self, _glue: Conjunction) -> Cow<str> {
-		Cow::Borrowed(self[0].as_ref())
+	#[test]
+	fn t_join_split() {
+		for (input, expected) in [
+			("", ""),
+			(",,, ,", ""),
+			("apples", "apples"),
+			(" apples ,", "apples"),
+			("apples,bananas", "apples and bananas"),
+			("apples, bananas, carrots", "apples, bananas, and carrots"),
+			(",apples,, bananas ,carrots,", "apples, bananas, and carrots"),
+		] {
+			assert_eq!(Conjunction::And.oxford_join_split(input, ','), expected);
+		}
+
+		// Other delimiters work too.
+		assert_eq!(
+			Conjunction::Or.oxford_join_split("a\r\nb\r\n\r\nc", '\n'),
+			"a, b, or c",
+		);
+		assert_eq!(Conjunction::And.oxford_join_split("日;本;語", ';'), "日, 本, and 語");
 	}
-}
 
-impl<T> OxfordJoin for [T; 2] where T: AsRef<str> {
-	#[expect(unsafe_code, reason = "Strings in, strings out.")]
-	#[inline]
-	/// # Oxford Join.
-	///
-	/// This is a special case; it will always read "first CONJUNCTION last".
-	fn oxford_join(&self, glue: Conjunction) -> Cow<str> {
-		let a = self[0].as_ref().as_bytes();
-		let b = self[1].as_ref().as_bytes();
+	#[test]
+	fn conjunction_len() {
+		for c in CTEST {
+			assert_eq!(c.len(), c.as_str().len());
+			assert_eq!(c.char_len(), c.as_str().chars().count());
+			assert!(! c.is_empty());
+		}
 
-		let len = a.len() + b.len() + 2 + glue.len();
-		let mut v = Vec::with_capacity(len);
-		v.extend_from_slice(a);  // First.
-		glue.append_two(&mut v); // Conjunction.
-		v.extend_from_slice(b);  // Last.
+		// Multi-byte.
+		for s in ["και", "和", "🍎 ou 🍌", "é"] {
+			assert_eq!(Conjunction::Other(s).char_len(), s.chars().count());
+		}
 
-		// Safety: strings in, strings out.
-		let out = unsafe { String::from_utf8_unchecked(v) };
-		Cow::Owned(out)
+		assert!(Conjunction::Other("").is_empty());
+
+		// Emptiness should always agree with length.
+		let raw = Conjunction::OtherRaw { pair: "", list: "" };
+		assert_eq!(raw.len(), 0);
+		assert!(raw.is_empty());
+		let raw = Conjunction::OtherRaw { pair: " ", list: " → " };
+		assert_eq!(raw.len(), 0);
+		assert!(raw.is_empty());
+		assert!(! Conjunction::OtherRaw { pair: " → ", list: " → " }.is_empty());
+		let dynamic = Conjunction::Dynamic(|_| "and");
+		assert_eq!(dynamic.len(), 0);
+		assert!(dynamic.is_empty());
 	}
-}
 
-/// # Join Arrays (3+).
-macro_rules! join_arrays {
-	($($num:literal $pad:literal $last:literal),+ $(,)?) => ($(
-		impl<T> OxfordJoin for [T; $num] where T: AsRef<str> {
-			#[expect(unsafe_code, reason = "Strings in, strings out.")]
-			/// # Oxford Join.
-			fn oxford_join(&self, glue: Conjunction) -> Cow<str> {
-				let len = glue.len() + $pad + self.iter().map(|x| x.as_ref().len()).sum::<usize>();
-				let [first, mid @ .., last] = self;
-				let mut v = Vec::with_capacity(len);
-
-				// Write the first.
-				v.extend_from_slice(first.as_ref().as_bytes());
-
-				// Write the middles.
-				for s in mid {
-					v.extend_from_slice(COMMASPACE);
-					v.extend_from_slice(s.as_ref().as_bytes());
-				}
+	#[test]
+	fn conjunction_try_inline() {
+		for (raw, expected) in [
+			("und", "und"),
+			("  und ", "und"),
+			("and/or", "and/or"),
+			("", ""),
+		] {
+			assert_eq!(Conjunction::try_inline(raw), Ok(Conjunction::Other(expected)));
+			assert_eq!(Conjunction::try_inline(raw), Ok(Conjunction::from(raw)));
+		}
 
-				// Write the conjunction and last.
-				glue.append_to(&mut v);
-				v.extend_from_slice(last.as_ref().as_bytes());
+		for (raw, chr, idx) in [
+			("\nand", '\n', 0),
+			("and\r\n", '\r', 3),
+			("an\td", '\t', 2),
+			("y\u{301} ó\n", '\n', 6),
+		] {
+			let err = Conjunction::try_inline(raw).unwrap_err();
+			assert_eq!(err.char(), chr);
+			assert_eq!(err.index(), idx);
+		}
+	}
 
-				// Safety: strings in, strings out.
-				let out = unsafe { String::from_utf8_unchecked(v) };
-				Cow::Owned(out)
-			}
+	#[test]
+	fn conjunction_glue_len() {
+		const PAIR: &str = "A, B";
+		const LEN: usize = Conjunction::AndOr.list_glue_len(); // Const-friendly.
+		assert_eq!(LEN, ", and/or ".len());
+
+		for c in CTEST.into_iter().chain([
+			Conjunction::OtherRaw { pair: "→", list: " ⇒ " },
+			Conjunction::Other("und"),
+		]) {
+			// Pairs.
+			let set = ["A", "B"];
+			let out = set.oxford_join(c);
+			assert_eq!(c.pair_glue_len(), out.len() - 2);
+			assert_eq!(c.pair_glue_len(), c.pair_parts().concat().len());
+
+			// Lists.
+			let set = ["A", "B", "C"];
+			let out = set.oxford_join(c);
+			assert_eq!(c.list_glue_len(), out.len() - PAIR.len() - 1);
+			assert_eq!(c.list_glue_len(), c.list_parts(", ").concat().len());
 		}
-	)+);
-}
 
-join_arrays!(
-	 3  5  2,
-	 4  7  3,
-	 5  9  4,
-	 6 11  5,
-	 7 13  6,
-	 8 15  7,
-	 9 17  8,
-	10 19  9,
-	11 21 10,
-	12 23 11,
-	13 25 12,
-	14 27 13,
-	15 29 14,
-	16 31 15,
-	17 33 16,
-	18 35 17,
-	19 37 18,
-	20 39 19,
-	21 41 20,
-	22 43 21,
-	23 45 22,
-	24 47 23,
-	25 49 24,
-	26 51 25,
-	27 53 26,
-	28 55 27,
-	29 57 28,
-	30 59 29,
-	31 61 30,
-	32 63 31,
-);
+		// Presets are padded.
+		assert_eq!(Conjunction::And.pair_glue_len(), " and ".len());
+		assert_eq!(Conjunction::And.list_glue_len(), ", and ".len());
+		assert_eq!(Conjunction::Ampersand.pair_glue_len(), " & ".len());
+		assert_eq!(Conjunction::Ampersand.list_glue_len(), ", & ".len());
+	}
 
-/// # Helper: Binary Tree Joins.
-macro_rules! join_btrees {
-	($iter:ident) => (
-		#[expect(unsafe_code, reason = "Strings in, strings out.")]
-		/// # Oxford Join.
-		fn oxford_join(&self, glue: Conjunction) -> Cow<str> {
-			match self.len() {
-				0 => Cow::Borrowed(""),
-				1 => Cow::Borrowed(self.$iter().next().unwrap().as_ref()),
-				2 => {
-					let mut iter = self.$iter();
-					let a = iter.next().unwrap().as_ref().as_bytes();
-					let b = iter.next().unwrap().as_ref().as_bytes();
+	#[test]
+	fn conjunction_default() {
+		const { assert!(matches!(Conjunction::DEFAULT, Conjunction::And)); }
+		const { assert!(Conjunction::DEFAULT.is_preset()); }
+		assert_eq!(Conjunction::DEFAULT, Conjunction::And);
+		assert_eq!(Conjunction::DEFAULT, Conjunction::default());
 
-					let len = a.len() + b.len() + 2 + glue.len();
-					let mut v = Vec::with_capacity(len);
-					v.extend_from_slice(a);  // First.
-					glue.append_two(&mut v); // Conjunction.
-					v.extend_from_slice(b);  // Last.
+		for c in Conjunction::ALL { assert!(c.is_preset()); }
+		for c in [
+			Conjunction::Other("and"),
+			Conjunction::OtherRaw { pair: " and ", list: ", and " },
+			Conjunction::Dynamic(|_| "and"),
+		] {
+			assert!(! c.is_preset());
+		}
+	}
 
-					// Safety: strings in, strings out.
-					let out = unsafe { String::from_utf8_unchecked(v) };
-					Cow::Owned(out)
-				},
-				n => {
-					let last = n - 1;
-					let len = glue.len() + 1 + last * 2 + self.$iter().map(|x| x.as_ref().len()).sum::<usize>();
+	#[test]
+	fn conjunction_semantic_eq() {
+		use alloc::collections::BTreeSet;
 
-					let mut v = Vec::with_capacity(len);
-					let mut iter = self.$iter();
+		assert!(Conjunction::And.semantic_eq(&Conjunction::Other("and")));
+		assert!(Conjunction::Other("and").semantic_eq(&Conjunction::And));
+		assert!(! Conjunction::And.semantic_eq(&Conjunction::Other("And")));
+		assert!(! Conjunction::And.semantic_eq(&Conjunction::Or));
 
-					// Write the first.
-					v.extend_from_slice(iter.next().unwrap().as_ref().as_bytes());
+		// Every (worded) preset should equal its Other twin, and nothing else.
+		for a in Conjunction::ALL {
+			assert_eq!(
+				a.semantic_eq(&Conjunction::Other(a.as_str())),
+				a != Conjunction::Comma,
+			);
+			for b in Conjunction::ALL {
+				assert_eq!(a.semantic_eq(&b), a == b);
+			}
+		}
 
-					// Write the middles. (Last is count minus one, but since
-					// we already wrote an entry, we need to subtract one
-					// again.)
-					for s in iter.by_ref().take(last - 1) {
-						v.extend_from_slice(COMMASPACE);
-						v.extend_from_slice(s.as_ref().as_bytes());
-					}
+		// Dynamic conjunctions only match themselves.
+		let dynamic = Conjunction::Dynamic(|_| "and");
+		assert!(dynamic.semantic_eq(&dynamic));
+		assert!(! dynamic.semantic_eq(&Conjunction::And));
+		assert!(! Conjunction::Other("").semantic_eq(&dynamic));
 
-					// Write the conjunction and last.
-					glue.append_to(&mut v);
-					v.extend_from_slice(iter.next().unwrap().as_ref().as_bytes());
+		// Equality should hold exactly when the output does.
+		let set = BTreeSet::from([
+			Conjunction::And,
+			Conjunction::Other("and"),
+			Conjunction::OtherRaw { pair: " and ", list: ", and " },
+			Conjunction::OtherRaw { pair: " and ", list: " and " },
+			Conjunction::Plus,
+		]);
+		for a in &set {
+			for b in &set {
+				let same =
+					["A", "B"].oxford_join(*a) == ["A", "B"].oxford_join(*b) &&
+					["A", "B", "C"].oxford_join(*a) == ["A", "B", "C"].oxford_join(*b);
+				assert_eq!(a.semantic_eq(b), same, "{a:?} vs {b:?}");
+			}
+		}
+	}
 
-					// Safety: strings in, strings out.
-					let out = unsafe { String::from_utf8_unchecked(v) };
-					Cow::Owned(out)
-				},
+	#[test]
+	fn conjunction_estimate_len() {
+		const SET: [&str; 5] = ["Apple", "Grape", "Lemon", "Mango", "Peach"];
+		for c in CTEST {
+			for len in 0..=SET.len() {
+				let set = &SET[..len];
+				assert_eq!(c.estimate_len(len, 5), set.oxford_join(c).len());
 			}
 		}
-	);
-}
 
-impl<K, T> OxfordJoin for BTreeMap<K, T> where T: AsRef<str> { join_btrees!(values); }
+		// Big numbers shouldn't overflow.
+		assert_eq!(Conjunction::And.estimate_len(usize::MAX, 2), usize::MAX);
+	}
 
-impl<T> OxfordJoin for BTreeSet<T> where T: AsRef<str> { join_btrees!(iter); }
+	#[test]
+	fn conjunction_all() {
+		// The number of presets; if this changes, ALL needs updating!
+		let presets = CTEST.iter()
+			.filter(|c| match c {
+				Conjunction::Ampersand | Conjunction::And | Conjunction::AndOr |
+				Conjunction::Comma | Conjunction::Nor | Conjunction::Or | Conjunction::Plus => true,
+				Conjunction::Other(_) | Conjunction::Dynamic(_) |
+				Conjunction::OtherRaw { .. } => false,
+			})
+			.count();
+		assert_eq!(Conjunction::ALL.len(), presets, "ALL is missing presets.");
 
+		// Names should be unique, present, and lowercase.
+		let mut names = BTreeSet::new();
+		for c in Conjunction::ALL {
+			let name = c.preset_name().expect("Missing preset name.");
+			assert_eq!(name, name.to_ascii_lowercase(), "Name is not lowercase.");
+			assert!(names.insert(name), "Duplicate preset name.");
+		}
 
+		assert!(Conjunction::Other("and").preset_name().is_none());
+	}
 
-#[cfg(test)]
-mod tests {
-	use super::*;
-	use brunch as _;
+	#[cfg(feature = "i18n")]
+	#[test]
+	fn conjunction_for_locale() {
+		for (tag, expected) in [
+			("de", Conjunction::Other("und")),
+			("de-CH", Conjunction::Other("und")),
+			("es", Conjunction::Other("y")),
+			("ES-mx", Conjunction::Other("y")),
+			("fr", Conjunction::Other("et")),
+			("pt_BR", Conjunction::Other("e")),
+			("sv", Conjunction::Other("och")),
+			("en", Conjunction::And),
+			("en-GB", Conjunction::And),
+			("xx", Conjunction::And),
+			("", Conjunction::And),
+			("-", Conjunction::And),
+		] {
+			assert_eq!(Conjunction::for_locale(tag), expected, "Tag: {tag}");
+		}
+	}
 
-	const CTEST: [Conjunction; 7] = [
-		Conjunction::Ampersand,
-		Conjunction::And,
-		Conjunction::AndOr,
-		Conjunction::Nor,
-		Conjunction::Or,
-		Conjunction::Other("Boo"),
-		Conjunction::Plus,
-	];
+	#[test]
+	fn conjunction_ord() {
+		// Ordering should agree with equality.
+		let mut all = CTEST.to_vec();
+		all.push(Conjunction::Other("and"));
+		all.push(Conjunction::Other("&"));
+		for a in &all {
+			for b in &all {
+				assert_eq!(a == b, a.cmp(b).is_eq(), "Ord/Eq mismatch.");
+				assert_eq!(a.cmp(b), b.cmp(a).reverse(), "Ord asymmetry.");
+			}
+		}
+
+		// Alphabetical, presets first.
+		all.sort();
+		assert_eq!(
+			all,
+			[
+				Conjunction::Ampersand,
+				Conjunction::Other("&"),
+				Conjunction::Plus,
+				Conjunction::Comma,
+				Conjunction::Other("Boo"),
+				Conjunction::And,
+				Conjunction::Other("and"),
+				Conjunction::AndOr,
+				Conjunction::Nor,
+				Conjunction::Or,
+			],
+		);
+
+		// Map keys work too.
+		let map: BTreeMap<Conjunction, usize> = all.iter().copied()
+			.enumerate()
+			.map(|(k, v)| (v, k))
+			.collect();
+		assert_eq!(map.len(), all.len());
+	}
 
 	#[test]
-	#[allow(clippy::cognitive_complexity)] // It is what it is.
-	fn t_fruit() {
+	fn conjunction_dynamic() {
 		use alloc::string::ToString;
 
-		// Make sure arrays, slices, vecs, boxes, etc., all work out the same
-		// way.
-		macro_rules! compare {
-			($($arr:ident, $expected:literal),+ $(,)?) => ($(
-				assert_eq!($arr.oxford_and(), $expected, "Array.");
-				assert_eq!($arr.as_slice().oxford_and(), $expected, "Slice.");
+		/// # Spanish "And".
+		fn y(next: &str) -> &'static str {
+			if next.starts_with(['i', 'I']) { "e" } else { "y" }
+		}
 
-				let v = $arr.to_vec();
-				assert_eq!(v.oxford_and(), $expected, "Vec.");
-				assert_eq!(v.into_boxed_slice().oxford_and(), $expected, "Box.");
+		/// # Spanish "Or".
+		fn o(next: &str) -> &'static str {
+			if next.starts_with(['o', 'O']) { "u" } else { "o" }
+		}
 
-				let v: BTreeMap<usize, &str> = $arr.into_iter().enumerate().collect();
-				assert_eq!(v.oxford_and(), $expected, "BTreeMap.");
+		const ARR2: [&str; 2] = ["Juan", "Inés"];
+		const ARR3: [&str; 3] = ["Juan", "María", "Inés"];
+		const ARR4: [&str; 4] = ["siete", "ocho", "nueve", "once"];
 
-				let v = BTreeSet::from($arr);
-				assert_eq!(v.oxford_and(), $expected, "BTreeSet.");
+		let glue = Conjunction::Dynamic(y);
+		let set = BTreeSet::from(ARR3);
+		for (out, expected) in [
+			(ARR2.oxford_join(glue), "Juan e Inés"),
+			(ARR2.as_slice().oxford_join(glue), "Juan e Inés"),
+			(ARR2[..1].oxford_join(glue), "Juan"),
+			(ARR3.oxford_join(glue), "Juan, María, e Inés"),
+			(ARR3.as_slice().oxford_join(glue), "Juan, María, e Inés"),
+			(set.oxford_join(glue), "Inés, Juan, y María"),
+			(ARR3.oxford_join_styled(JoinStyle::new(glue).with_separator("; ")), "Juan; María; e Inés"),
+			(Cow::Owned(glue.oxford_join(ARR3)), "Juan, María, e Inés"),
+			(Cow::Owned(ARR3.to_vec().into_oxford_join(glue)), "Juan, María, e Inés"),
+			(Cow::Owned(OxfordJoinFmt::new(&ARR3, glue).to_string()), "Juan, María, e Inés"),
+			(Cow::Owned(OxfordJoinFmt::new(&ARR2, glue).to_string()), "Juan e Inés"),
+		] {
+			assert_eq!(out, expected, "Dynamic join mismatch.");
+		}
 
-				assert_eq!(
-					OxfordJoinFmt::and($arr.as_slice()).to_string(),
-					$expected,
-					"OxfordJoinFmt::to_string",
-				);
-			)+);
+		let glue = Conjunction::Dynamic(o);
+		assert_eq!(ARR4.oxford_join(glue), "siete, ocho, nueve, u once");
+		assert_eq!(ARR4[..3].oxford_join(glue), "siete, ocho, o nueve");
+		assert_eq!(ARR4[1..].oxford_join(glue), "ocho, nueve, u once");
+
+		// Resolution.
+		assert_eq!(glue.for_last("once"), Conjunction::Other("u"));
+		assert_eq!(glue.as_str(), "");
+		assert_ne!(Conjunction::Dynamic(o), Conjunction::Dynamic(y));
+		assert_eq!(Conjunction::Dynamic(o), Conjunction::Dynamic(o));
+		assert_ne!(Conjunction::Dynamic(o).cmp(&Conjunction::Dynamic(y)), Ordering::Equal);
+	}
+
+	#[test]
+	fn conjunction_other_raw() {
+		use alloc::string::ToString;
+
+		const ARR1: [&str; 1] = ["A"];
+		const ARR2: [&str; 2] = ["A", "B"];
+		const ARR3: [&str; 3] = ["A", "B", "C"];
+		const ARR5: [&str; 5] = ["A", "B", "C", "D", "E"];
+
+		let glue = Conjunction::OtherRaw { pair: "→", list: " ⇒ " };
+		let set = BTreeSet::from(ARR3);
+		for (out, expected) in [
+			(ARR1.oxford_join(glue), "A"),
+			(ARR2.oxford_join(glue), "A→B"),
+			(ARR2.as_slice().oxford_join(glue), "A→B"),
+			(ARR3.oxford_join(glue), "A, B ⇒ C"),
+			(ARR3.as_slice().oxford_join(glue), "A, B ⇒ C"),
+			(ARR5.oxford_join(glue), "A, B, C, D ⇒ E"),
+			(ARR5.as_slice().oxford_join(glue), "A, B, C, D ⇒ E"),
+			(set.oxford_join(glue), "A, B ⇒ C"),
+			(ARR3.oxford_join_styled(JoinStyle::new(glue).with_separator("; ")), "A; B ⇒ C"),
+			(ARR2.oxford_join_styled(JoinStyle::new(glue).with_tight()), "A→B"),
+			(ARR5.oxford_join_repeated(glue), "A→B→C→D→E"),
+			(Cow::Owned(glue.oxford_join(ARR3)), "A, B ⇒ C"),
+			(Cow::Owned(glue.oxford_join(ARR2)), "A→B"),
+			(Cow::Owned(ARR3.to_vec().into_oxford_join(glue)), "A, B ⇒ C"),
+			(Cow::Owned(ARR2.into_oxford_join(glue)), "A→B"),
+			(Cow::Owned(OxfordJoinFmt::new(&ARR3, glue).to_string()), "A, B ⇒ C"),
+			(Cow::Owned(OxfordJoinFmt::new(&ARR2, glue).to_string()), "A→B"),
+		] {
+			assert_eq!(out, expected, "Raw join mismatch.");
 		}
 
-		const ARR0: [&str; 0] = [];
-		const ARR1: [&str; 1] = ["Apples"];
-		const ARR2: [&str; 2] = ["Apples", "Bananas"];
-		const ARR3: [&str; 3] = ["Apples", "Bananas", "Carrots"];
-		const ARR4: [&str; 4] = ["Apples", "Bananas", "Carrots", "Dates"];
-		const ARR5: [&str; 5] = ["Apples", "Bananas", "Carrots", "Dates", "Eggplant"];
-		const ARR32: [&str; 32] = [
-			"0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "A", "B", "C", "D", "E", "F",
-			"G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S", "T", "U", "V",
-		];
+		// Capacity should be exact.
+		let out = ARR5.as_slice().oxford_join(glue);
+		assert_eq!(out.len(), glue.estimate_len(5, 1));
+		assert_eq!(ARR2.oxford_join(glue).len(), glue.estimate_len(2, 1));
 
-		compare!(
-			ARR0, "",
-			ARR1, "Apples",
-			ARR2, "Apples and Bananas",
-			ARR3, "Apples, Bananas, and Carrots",
-			ARR4, "Apples, Bananas, Carrots, and Dates",
-			ARR5, "Apples, Bananas, Carrots, Dates, and Eggplant",
-			ARR32, "0, 1, 2, 3, 4, 5, 6, 7, 8, 9, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, and V",
+		// Meta.
+		let glue = Conjunction::OtherRaw { pair: " → ", list: ", → " };
+		assert_eq!(glue.as_str(), "→");
+		assert_eq!(glue.len(), "→".len());
+		assert_eq!(glue.preset_name(), None);
+		assert!(! glue.is_symbol());
+		assert_ne!(glue, Conjunction::Other("→"));
+		assert_ne!(glue, Conjunction::OtherRaw { pair: " → ", list: " → " });
+		assert_ne!(
+			glue.cmp(&Conjunction::OtherRaw { pair: " → ", list: " → " }),
+			Ordering::Equal,
 		);
+		assert_eq!(ARR3.oxford_join(glue), "A, B, → C");
 	}
 
 	#[test]
-	fn conjunction_len() {
-		for c in CTEST {
-			assert_eq!(c.len(), c.as_str().len());
-			assert!(! c.is_empty());
+	fn conjunction_comma() {
+		use alloc::string::ToString;
+
+		const SET: [&str; 4] = ["a", "b", "c", "d"];
+		let glue = Conjunction::Comma;
+		assert_eq!(glue.as_str(), ",");
+		assert_eq!(glue.len(), 1);
+		assert_eq!(glue.pair_glue_len(), 2);
+		assert_eq!(glue.list_glue_len(), 2);
+		assert!(! glue.is_symbol());
+		assert_eq!(glue.correlative(), None);
+
+		// Every item is comma-separated, pairs included.
+		for len in 0..=SET.len() {
+			let set = &SET[..len];
+			let expected = set.join(", ");
+			assert_eq!(set.oxford_join(glue), expected);
+			assert_eq!(set.to_vec().oxford_join(glue), expected);
+			assert_eq!(set.iter().copied().collect::<BTreeSet<_>>().oxford_join(glue), expected);
+			assert_eq!(glue.oxford_join(set), expected);
+			assert_eq!(OxfordJoinFmt::new(set, glue).to_string(), expected);
 		}
 
-		assert!(Conjunction::Other("").is_empty());
+		// Custom separators apply throughout.
+		assert_eq!(
+			SET.oxford_join_styled(JoinStyle::new(glue).with_separator("; ")),
+			"a; b; c; d",
+		);
+
+		// It is not the same as a raw comma.
+		assert!(! glue.semantic_eq(&Conjunction::Other(",")));
+		assert!(glue.semantic_eq(&Conjunction::OtherRaw { pair: ", ", list: ", " }));
 	}
 
 	#[test]
 	fn conjunction_append() {
 		for c in CTEST {
 			// Two.
-			let s = [" ", c.as_str(), " "].concat();
+			let s = c.pair_parts().concat();
 			let mut v = Vec::new();
 			c.append_two(&mut v);
 			assert_eq!(v, s.as_bytes());
 
 			// Three+.
-			let s = [", ", c.as_str(), " "].concat();
+			let s = c.list_parts(", ").concat();
 			v.truncate(0);
 			c.append_to(&mut v);
 			assert_eq!(v, s.as_bytes());
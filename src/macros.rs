@@ -0,0 +1,344 @@
+/*!
+# Oxford Join: Macros.
+*/
+
+
+
+#[macro_export]
+/// # Oxford Join (Compile-Time).
+///
+/// Join string literals with Oxford Commas at compile time, producing a
+/// `&'static str` suitable for use in `const` contexts. There is no runtime
+/// cost whatsoever.
+///
+/// The first argument is the conjunction — either the name of a
+/// [`Conjunction`](crate::Conjunction) preset like `And`, or a string literal
+/// — followed by a semicolon and the items to join.
+///
+/// When all of the items are literals, the work is done by [`concat!`].
+/// (Non-string literals like numbers will be stringified.)
+///
+/// ## Runtime Joins
+///
+/// If any of the items are _not_ literals, the macro instead expands to a
+/// runtime join — returning a `String` — of any mix of [`AsRef<str>`] types,
+/// no temporary collection of a single element type required. Each item is
+/// evaluated exactly once, and the output is allocated exactly once.
+///
+/// In this mode the conjunction can also be any [`Conjunction`](crate::Conjunction)
+/// expression, like `Conjunction::Dynamic(…)`.
+///
+/// Runtime joins require the `alloc` crate feature.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::oxford_join;
+///
+/// const FRUIT: &str = oxford_join!(And; "Apples", "Oranges", "Bananas");
+/// assert_eq!(FRUIT, "Apples, Oranges, and Bananas");
+///
+/// const PAIR: &str = oxford_join!(Or; "Apples", "Oranges");
+/// assert_eq!(PAIR, "Apples or Oranges");
+///
+/// // Custom conjunctions work too.
+/// const CUSTOM: &str = oxford_join!("plus or minus"; 1, 2, 3);
+/// assert_eq!(CUSTOM, "1, 2, plus or minus 3");
+///
+/// // Small sets are small.
+/// assert_eq!(oxford_join!(And; "Apples"), "Apples");
+/// assert_eq!(oxford_join!(And;), "");
+/// ```
+///
+/// Mixed types:
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use oxford_join::{Conjunction, oxford_join};
+/// use std::borrow::Cow;
+///
+/// let b = String::from("Oranges");
+/// let c = 3_u8;
+/// let d = Cow::Borrowed("Pears");
+/// assert_eq!(
+///     oxford_join!(Conjunction::And; "Apples", b, c.to_string(), d),
+///     "Apples, Oranges, 3, and Pears",
+/// );
+///
+/// // Preset names and custom literals work here too.
+/// assert_eq!(oxford_join!(Or; "Apples", b), "Apples or Oranges");
+/// assert_eq!(oxford_join!("und"; "Apples", b), "Apples und Oranges");
+/// # }
+/// ```
+macro_rules! oxford_join {
+	// Conjunctions.
+	(@glue Ampersand) => ( "&" );
+	(@glue And) => ( "and" );
+	(@glue AndOr) => ( "and/or" );
+	(@glue Nor) => ( "nor" );
+	(@glue Or) => ( "or" );
+	(@glue Plus) => ( "+" );
+	(@glue $glue:literal) => ( $glue );
+
+	// Conjunctions (Runtime).
+	(@rt_glue Ampersand) => ( $crate::Conjunction::Ampersand );
+	(@rt_glue And) => ( $crate::Conjunction::And );
+	(@rt_glue AndOr) => ( $crate::Conjunction::AndOr );
+	(@rt_glue Comma) => ( $crate::Conjunction::Comma );
+	(@rt_glue Nor) => ( $crate::Conjunction::Nor );
+	(@rt_glue Or) => ( $crate::Conjunction::Or );
+	(@rt_glue Plus) => ( $crate::Conjunction::Plus );
+	(@rt_glue $glue:literal) => ( $crate::Conjunction::Other($glue) );
+	(@rt_glue $glue:expr) => ( $glue );
+
+	// Runtime joins. Temporaries in a match scrutinee live until the end of
+	// the match, so the items can be borrowed as a stack array of `&str`.
+	(@rt $glue:expr; $($item:expr),+) => (
+		match [$(<_ as ::core::convert::AsRef<str>>::as_ref(&$item)),+] {
+			set => $crate::OxfordJoin::oxford_join(&set[..], $glue).into_owned(),
+		}
+	);
+
+	// Three or more, one at a time.
+	(@many $glue:tt; [$($acc:tt)+] $last:literal) => (
+		concat!($($acc)+, ", ", $crate::oxford_join!(@glue $glue), " ", $last)
+	);
+	(@many $glue:tt; [$($acc:tt)+] $next:literal, $($rest:literal),+) => (
+		$crate::oxford_join!(@many $glue; [$($acc)+, ", ", $next] $($rest),+)
+	);
+
+	// Entrypoints.
+	(Comma; $first:literal, $($rest:literal),+ $(,)?) => (
+		concat!($first, $(", ", $rest),+)
+	);
+	($glue:tt; $(,)?) => ( "" );
+	($glue:tt; $only:literal $(,)?) => ( concat!($only) );
+	($glue:tt; $first:literal, $last:literal $(,)?) => (
+		concat!($first, " ", $crate::oxford_join!(@glue $glue), " ", $last)
+	);
+	($glue:tt; $first:literal, $($rest:literal),+ $(,)?) => (
+		$crate::oxford_join!(@many $glue; [$first] $($rest),+)
+	);
+	($glue:tt; $($item:expr),+ $(,)?) => (
+		$crate::oxford_join!(@rt $crate::oxford_join!(@rt_glue $glue); $($item),+)
+	);
+	($glue:expr; $($item:expr),+ $(,)?) => (
+		$crate::oxford_join!(@rt $glue; $($item),+)
+	);
+}
+
+
+
+#[macro_export]
+/// # Implement `OxfordJoin`.
+///
+/// Generate an [`OxfordJoin`](crate::OxfordJoin) implementation for a custom
+/// collection type, given the names of its iteration and length methods.
+///
+/// The iteration method must take `&self` and return an iterator of
+/// references to [`AsRef<str>`] items; the length method must take `&self`
+/// and return the number of items as a `usize`.
+///
+/// The generated implementation is built atop the same count-then-walk
+/// machinery as [`OxfordJoin::oxford_join_styled`](crate::OxfordJoin::oxford_join_styled),
+/// so the output is allocated exactly once, and empty and single-item sets
+/// are borrowed rather than copied.
+///
+/// Generic types can be supported by prefixing the type with its generic
+/// parameters (and bounds) in square brackets, e.g.
+/// `[T: AsRef<str>] MyVec<T>`.
+///
+/// Note that Rust's orphan rules still apply: this can't be used on types
+/// from _other_ crates, but it does make wrapping them a one-liner.
+///
+/// This requires the `alloc` crate feature.
+///
+/// ## Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use oxford_join::{Conjunction, OxfordJoin};
+///
+/// /// # Shopping List.
+/// struct Groceries(Vec<String>);
+///
+/// impl Groceries {
+///     fn items(&self) -> impl Iterator<Item=&String> { self.0.iter() }
+///     fn count(&self) -> usize { self.0.len() }
+/// }
+///
+/// oxford_join::impl_oxford_join!(Groceries, items, count);
+///
+/// let list = Groceries(vec!["Apples".to_owned(), "Bananas".to_owned()]);
+/// assert_eq!(list.oxford_join(Conjunction::And), "Apples and Bananas");
+///
+/// // All the other trait methods come along for free.
+/// assert_eq!(list.oxford_join_prefixed(Conjunction::Or, "Green "), "Green Apples or Green Bananas");
+/// # }
+/// ```
+///
+/// Generics:
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use oxford_join::OxfordJoin;
+///
+/// struct Stack<T>(Vec<T>);
+///
+/// impl<T> Stack<T> {
+///     fn iter(&self) -> impl Iterator<Item=&T> { self.0.iter().rev() }
+///     fn len(&self) -> usize { self.0.len() }
+/// }
+///
+/// oxford_join::impl_oxford_join!([T: AsRef<str>] Stack<T>, iter, len);
+///
+/// let stack = Stack(vec!["Apples", "Bananas", "Carrots"]);
+/// assert_eq!(stack.oxford_and(), "Carrots, Bananas, and Apples");
+/// # }
+/// ```
+macro_rules! impl_oxford_join {
+	([$($gen:tt)*] $ty:ty, $iter:ident, $len:ident $(,)?) => (
+		impl<$($gen)*> $crate::OxfordJoin for $ty {
+			#[inline]
+			fn oxford_join(&self, glue: $crate::Conjunction)
+			-> $crate::__private::Cow<'_, str> {
+				$crate::OxfordJoin::oxford_join_styled(self, $crate::JoinStyle::new(glue))
+			}
+
+			#[inline]
+			fn oxford_count(&self) -> usize { self.$len() }
+
+			#[inline]
+			fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+				for v in self.$iter() { cb(::core::convert::AsRef::<str>::as_ref(v)); }
+			}
+		}
+	);
+	($ty:ty, $iter:ident, $len:ident $(,)?) => (
+		$crate::impl_oxford_join!([] $ty, $iter, $len);
+	);
+}
+
+
+
+#[cfg(test)]
+mod test {
+	#[test]
+	fn t_oxford_join_macro() {
+		const EMPTY: &str = oxford_join!(And;);
+		const ONE: &str = oxford_join!(And; "Apples");
+		const TWO: &str = oxford_join!(And; "Apples", "Bananas");
+		const THREE: &str = oxford_join!(And; "Apples", "Bananas", "Carrots");
+		const FIVE: &str = oxford_join!(And; "Apples", "Bananas", "Carrots", "Dates", "Eggplant",);
+
+		assert_eq!(EMPTY, "");
+		assert_eq!(ONE, "Apples");
+		assert_eq!(TWO, "Apples and Bananas");
+		assert_eq!(THREE, "Apples, Bananas, and Carrots");
+		assert_eq!(FIVE, "Apples, Bananas, Carrots, Dates, and Eggplant");
+
+		// All the presets.
+		assert_eq!(oxford_join!(Ampersand; "a", "b", "c"), "a, b, & c");
+		assert_eq!(oxford_join!(AndOr; "a", "b", "c"), "a, b, and/or c");
+		assert_eq!(oxford_join!(Comma; "a", "b", "c"), "a, b, c");
+		assert_eq!(oxford_join!(Comma; "a", "b"), "a, b");
+		assert_eq!(oxford_join!(Comma; "a"), "a");
+		assert_eq!(oxford_join!(Nor; "a", "b", "c"), "a, b, nor c");
+		assert_eq!(oxford_join!(Or; "a", "b", "c"), "a, b, or c");
+		assert_eq!(oxford_join!(Plus; "a", "b", "c"), "a, b, + c");
+
+		// And a custom one.
+		assert_eq!(oxford_join!("und"; "a", "b"), "a und b");
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn t_oxford_join_macro_rt() {
+		use alloc::{
+			borrow::Cow,
+			string::{
+				String,
+				ToString,
+			},
+		};
+		use crate::{
+			Conjunction,
+			OxfordJoin,
+		};
+
+		let apples = "Apples";
+		let bananas = String::from("Bananas");
+		let carrots = Cow::Borrowed("Carrots");
+		let four = 4_u8;
+
+		let out: String = oxford_join!(Conjunction::And; apples);
+		assert_eq!(out, "Apples");
+		assert_eq!(oxford_join!(Conjunction::And; apples, bananas), "Apples and Bananas");
+		assert_eq!(
+			oxford_join!(Conjunction::And; apples, "Bananas", carrots, four.to_string(),),
+			"Apples, Bananas, Carrots, and 4",
+		);
+
+		// Exact capacity.
+		let out = oxford_join!(Conjunction::Or; "Apples", bananas, carrots, four.to_string());
+		assert_eq!(out, "Apples, Bananas, Carrots, or 4");
+		assert_eq!(out.len(), out.capacity());
+
+		// Presets, custom literals, and arbitrary expressions should all
+		// match the trait's output.
+		for glue in Conjunction::ALL {
+			assert_eq!(oxford_join!(glue; apples, bananas, carrots), [apples, "Bananas", "Carrots"].oxford_join(glue));
+		}
+		assert_eq!(oxford_join!(Plus; apples, bananas, carrots), "Apples, Bananas, + Carrots");
+		assert_eq!(oxford_join!("und"; apples, bananas), "Apples und Bananas");
+		assert_eq!(
+			oxford_join!(Conjunction::Other("plus"); apples, bananas, carrots),
+			"Apples, Bananas, plus Carrots",
+		);
+
+		// Each item should only be evaluated once.
+		let mut n = 0;
+		let mut next = || { n += 1; n.to_string() };
+		assert_eq!(oxford_join!(And; next(), next(), next()), "1, 2, and 3");
+		assert_eq!(n, 3);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn t_impl_oxford_join() {
+		use alloc::{
+			borrow::Cow,
+			collections::VecDeque,
+			string::String,
+		};
+		use crate::OxfordJoin;
+
+		/// # Wrapper.
+		struct List(VecDeque<String>);
+		impl List {
+			fn iter(&self) -> impl Iterator<Item=&String> { self.0.iter() }
+			fn len(&self) -> usize { self.0.len() }
+		}
+		impl_oxford_join!(List, iter, len);
+
+		/// # Generic Wrapper.
+		struct Generic<T>(VecDeque<T>);
+		impl<T> Generic<T> {
+			fn iter(&self) -> impl Iterator<Item=&T> { self.0.iter() }
+			fn len(&self) -> usize { self.0.len() }
+		}
+		impl_oxford_join!([T: AsRef<str>] Generic<T>, iter, len);
+
+		crate::tests::cross_check(|set, c| {
+			let list = List(set.iter().copied().map(String::from).collect());
+			assert_eq!(list.oxford_count(), set.len());
+			match list.oxford_join(c) {
+				Cow::Borrowed(_) => assert!(set.len() < 2, "Big sets should be owned."),
+				Cow::Owned(out) => assert_eq!(out.len(), out.capacity()),
+			}
+
+			let list = Generic(set.iter().copied().collect());
+			list.oxford_join(c).into_owned()
+		});
+	}
+}
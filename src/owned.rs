@@ -0,0 +1,128 @@
+/*!
+# Oxford Join: Owned Conjunctions.
+*/
+
+use alloc::string::String;
+use crate::Conjunction;
+use core::fmt;
+
+
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # Owned Conjunction.
+///
+/// [`Conjunction::Other`] borrows its text, which can make it awkward to build
+/// from runtime values like user input. This enum is an owned alternative,
+/// able to hold a `String` outright.
+///
+/// Joins still work with [`Conjunction`]s, so use
+/// [`OwnedConjunction::as_conjunction`] (or `From`) to borrow one when the
+/// time comes.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, OwnedConjunction, OxfordJoin};
+///
+/// fn load_conjunction() -> OwnedConjunction {
+///     let raw = String::from(" und ");
+///     OwnedConjunction::from(raw)
+/// }
+///
+/// let glue = load_conjunction();
+/// assert_eq!(glue.as_conjunction(), Conjunction::Other("und"));
+/// assert_eq!(
+///     ["Äpfel", "Birnen"].oxford_join(glue.as_conjunction()),
+///     "Äpfel und Birnen",
+/// );
+/// ```
+pub enum OwnedConjunction {
+	/// # Borrowable.
+	///
+	/// Any [`Conjunction`] that doesn't need owning: presets, dynamics, and
+	/// static custom values.
+	Static(Conjunction<'static>),
+
+	/// # Custom Entry (Owned, Trimmed).
+	Other(String),
+}
+
+impl Default for OwnedConjunction {
+	#[inline]
+	fn default() -> Self { Self::Static(Conjunction::default()) }
+}
+
+impl fmt::Display for OwnedConjunction {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_conjunction().as_str())
+	}
+}
+
+impl From<Conjunction<'static>> for OwnedConjunction {
+	#[inline]
+	fn from(src: Conjunction<'static>) -> Self { Self::Static(src) }
+}
+
+impl From<String> for OwnedConjunction {
+	fn from(mut src: String) -> Self {
+		// Trim in place, same as Conjunction::from(&str).
+		let end = src.trim_end().len();
+		src.truncate(end);
+		let start = src.len() - src.trim_start().len();
+		src.drain(..start);
+		Self::Other(src)
+	}
+}
+
+impl<'a> From<&'a OwnedConjunction> for Conjunction<'a> {
+	#[inline]
+	fn from(src: &'a OwnedConjunction) -> Self { src.as_conjunction() }
+}
+
+impl OwnedConjunction {
+	#[must_use]
+	/// # As Conjunction.
+	///
+	/// Return a borrowing [`Conjunction`] for use with the various join
+	/// methods.
+	pub fn as_conjunction(&self) -> Conjunction<'_> {
+		match self {
+			Self::Static(c) => *c,
+			Self::Other(s) => Conjunction::Other(s),
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::borrow::ToOwned;
+
+	#[test]
+	fn t_owned_conjunction() {
+		for c in Conjunction::ALL {
+			let owned = OwnedConjunction::from(c);
+			assert_eq!(owned.as_conjunction(), c);
+			assert_eq!(Conjunction::from(&owned), c);
+			assert_eq!(alloc::format!("{owned}"), c.as_str());
+		}
+
+		for (raw, expected) in [
+			("und", "und"),
+			("  und", "und"),
+			("und  ", "und"),
+			(" und oder ", "und oder"),
+			("   ", ""),
+		] {
+			let owned = OwnedConjunction::from(raw.to_owned());
+			assert_eq!(owned, OwnedConjunction::Other(expected.to_owned()));
+			assert_eq!(owned.as_conjunction(), Conjunction::Other(expected));
+			assert_eq!(owned.as_conjunction(), Conjunction::from(raw));
+		}
+
+		assert_eq!(OwnedConjunction::default().as_conjunction(), Conjunction::And);
+	}
+}
@@ -0,0 +1,188 @@
+/*!
+# Oxford Join: Resolved Conjunctions.
+*/
+
+use alloc::{
+	borrow::Cow,
+	string::String,
+};
+use crate::{
+	Conjunction,
+	OxfordJoin,
+};
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Resolved Conjunction.
+///
+/// This is a [`Conjunction`] with its two-item (`" and "`) and three-plus
+/// (`", and "`) glue strings — spaces, commas, and all — built up front,
+/// making it a cheap-to-reuse handle when joining many sets with the same
+/// conjunction.
+///
+/// The presets and [`Conjunction::OtherRaw`] resolve without allocating;
+/// [`Conjunction::Other`] values allocate once, here, rather than being
+/// re-padded for every join.
+///
+/// [`Conjunction::Dynamic`] values can't be resolved until the last item is
+/// known, so are simply passed through to the usual join machinery.
+///
+/// Use [`Conjunction::resolve`] to create one.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::Conjunction;
+///
+/// let glue = Conjunction::Other("und").resolve();
+/// assert_eq!(glue.pair_glue(), " und ");
+/// assert_eq!(glue.list_glue(), ", und ");
+///
+/// for (set, expected) in [
+///     (&["Äpfel", "Birnen"][..], "Äpfel und Birnen"),
+///     (&["Äpfel", "Birnen", "Kiwis"][..], "Äpfel, Birnen, und Kiwis"),
+/// ] {
+///     assert_eq!(glue.oxford_join(set), expected);
+/// }
+/// ```
+pub struct ResolvedConjunction<'a> {
+	/// # Source.
+	src: Conjunction<'a>,
+
+	/// # Two-Item Glue.
+	pair: Cow<'a, str>,
+
+	/// # Three+ Final Glue.
+	list: Cow<'a, str>,
+}
+
+impl<'a> From<Conjunction<'a>> for ResolvedConjunction<'a> {
+	#[inline]
+	fn from(src: Conjunction<'a>) -> Self { src.resolve() }
+}
+
+impl<'a> ResolvedConjunction<'a> {
+	/// # New.
+	pub(crate) fn new(src: Conjunction<'a>) -> Self {
+		let (pair, list) = match src {
+			Conjunction::Ampersand => (Cow::Borrowed(" & "), Cow::Borrowed(", & ")),
+			Conjunction::And => (Cow::Borrowed(" and "), Cow::Borrowed(", and ")),
+			Conjunction::AndOr => (Cow::Borrowed(" and/or "), Cow::Borrowed(", and/or ")),
+			Conjunction::Comma => (Cow::Borrowed(", "), Cow::Borrowed(", ")),
+			Conjunction::Nor => (Cow::Borrowed(" nor "), Cow::Borrowed(", nor ")),
+			Conjunction::Or => (Cow::Borrowed(" or "), Cow::Borrowed(", or ")),
+			Conjunction::Plus => (Cow::Borrowed(" + "), Cow::Borrowed(", + ")),
+			Conjunction::OtherRaw { pair, list } => (Cow::Borrowed(pair), Cow::Borrowed(list)),
+			Conjunction::Dynamic(_) => (Cow::Borrowed(""), Cow::Borrowed("")),
+			Conjunction::Other(s) => {
+				// The pair glue is the list glue minus the comma.
+				let mut list = String::with_capacity(s.len() + 3);
+				list.push_str(", ");
+				list.push_str(s);
+				list.push(' ');
+				let pair = String::from(&list[1..]);
+				(Cow::Owned(pair), Cow::Owned(list))
+			},
+		};
+
+		Self { src, pair, list }
+	}
+
+	#[must_use]
+	/// # Conjunction.
+	///
+	/// Return the original conjunction.
+	pub const fn conjunction(&self) -> Conjunction<'a> { self.src }
+
+	#[must_use]
+	/// # Pair Glue.
+	///
+	/// Return the full glue used to bind two-item sets, e.g. `" and "`.
+	///
+	/// For [`Conjunction::Dynamic`] values, this is empty.
+	pub fn pair_glue(&self) -> &str { &self.pair }
+
+	#[must_use]
+	/// # List Glue.
+	///
+	/// Return the full glue used to bind the last item of three-plus sets,
+	/// e.g. `", and "`.
+	///
+	/// For [`Conjunction::Dynamic`] values, this is empty.
+	pub fn list_glue(&self) -> &str { &self.list }
+
+	#[must_use]
+	/// # Oxford Join.
+	///
+	/// Join the set with Oxford Commas as necessary, exactly as
+	/// [`OxfordJoin::oxford_join`] would with the original conjunction.
+	pub fn oxford_join<'b, T: AsRef<str>>(&self, set: &'b [T]) -> Cow<'b, str> {
+		// Dynamic glue has to be resolved for each set.
+		if matches!(self.src, Conjunction::Dynamic(_)) {
+			return set.oxford_join(self.src);
+		}
+
+		match set {
+			[] => Cow::Borrowed(""),
+			[one] => Cow::Borrowed(one.as_ref()),
+			[a, b] => {
+				let (a, b) = (a.as_ref(), b.as_ref());
+				let mut out = String::with_capacity(a.len().saturating_add(self.pair.len()).saturating_add(b.len()));
+				out.push_str(a);
+				out.push_str(&self.pair);
+				out.push_str(b);
+				Cow::Owned(out)
+			},
+			[first, mid @ .., last] => {
+				let len = set.iter()
+					.map(|s| s.as_ref().len())
+					.fold(self.list.len().saturating_add(mid.len() * 2), usize::saturating_add);
+				let mut out = String::with_capacity(len);
+				out.push_str(first.as_ref());
+				for s in mid {
+					out.push_str(", ");
+					out.push_str(s.as_ref());
+				}
+				out.push_str(&self.list);
+				out.push_str(last.as_ref());
+				Cow::Owned(out)
+			},
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_resolved() {
+		crate::tests::cross_check(|set, c| {
+			let out = c.resolve().oxford_join(set);
+			if let Cow::Owned(ref out) = out { assert_eq!(out.len(), out.capacity()); }
+			out
+		});
+
+		for c in Conjunction::ALL.into_iter().chain([
+			Conjunction::Other("und"),
+			Conjunction::Other("y/o"),
+			Conjunction::OtherRaw { pair: "→", list: " ⇒ " },
+			Conjunction::Dynamic(|s| if s.starts_with('I') { "e" } else { "y" }),
+		]) {
+			let resolved = c.resolve();
+			assert_eq!(resolved.conjunction(), c);
+			if ! matches!(c, Conjunction::Dynamic(_)) {
+				assert_eq!(resolved.pair_glue().len(), c.pair_glue_len());
+				assert_eq!(resolved.list_glue().len(), c.list_glue_len());
+			}
+		}
+
+		// Others should be padded.
+		let resolved = ResolvedConjunction::from(Conjunction::Other("und"));
+		assert_eq!(resolved.pair_glue(), " und ");
+		assert_eq!(resolved.list_glue(), ", und ");
+	}
+}
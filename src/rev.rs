@@ -0,0 +1,136 @@
+/*!
+# Oxford Join: Reverse Joins.
+*/
+
+use alloc::{
+	borrow::Cow,
+	collections::{
+		BTreeMap,
+		BTreeSet,
+	},
+};
+use core::marker::PhantomData;
+use crate::{
+	Conjunction,
+	JoinStyle,
+	OxfordJoin,
+};
+
+
+
+/// # Oxford Join (Reverse).
+///
+/// This extension trait joins slices and ordered collections _back to
+/// front_, handy for emitting sets and maps in descending order without
+/// first collecting them into a `Vec`.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordJoinRev};
+/// use std::collections::{BTreeMap, BTreeSet};
+///
+/// let set = BTreeSet::from(["Apples", "Bananas", "Carrots"]);
+/// assert_eq!(
+///     set.oxford_join_rev(Conjunction::And),
+///     "Carrots, Bananas, and Apples",
+/// );
+///
+/// let map = BTreeMap::from([(1, "Gold"), (2, "Silver"), (3, "Bronze")]);
+/// assert_eq!(
+///     map.oxford_join_rev(Conjunction::Or),
+///     "Bronze, Silver, or Gold",
+/// );
+///
+/// let arr = ["One", "Two"];
+/// assert_eq!(arr.oxford_join_rev(Conjunction::And), "Two and One");
+/// ```
+pub trait OxfordJoinRev {
+	/// # Oxford Join (Reverse).
+	///
+	/// Join the items in reverse order with Oxford Commas as necessary.
+	fn oxford_join_rev(&self, glue: Conjunction) -> Cow<'_, str>;
+}
+
+impl<T: AsRef<str>> OxfordJoinRev for [T] {
+	#[inline]
+	fn oxford_join_rev(&self, glue: Conjunction) -> Cow<'_, str> {
+		join_rev(self.iter().rev().map(T::as_ref), glue)
+	}
+}
+
+impl<K, T: AsRef<str>> OxfordJoinRev for BTreeMap<K, T> {
+	#[inline]
+	fn oxford_join_rev(&self, glue: Conjunction) -> Cow<'_, str> {
+		join_rev(self.values().rev().map(T::as_ref), glue)
+	}
+}
+
+impl<T: AsRef<str>> OxfordJoinRev for BTreeSet<T> {
+	#[inline]
+	fn oxford_join_rev(&self, glue: Conjunction) -> Cow<'_, str> {
+		join_rev(self.iter().rev().map(T::as_ref), glue)
+	}
+}
+
+
+
+/// # Reverse Wrapper.
+///
+/// This wraps a (reversed) iterator so it can be fed to the usual join
+/// machinery. It is cloned for each pass.
+struct Rev<'a, I>(I, PhantomData<&'a str>);
+
+impl<'a, I> OxfordJoin for Rev<'a, I>
+where I: ExactSizeIterator<Item=&'a str> + Clone {
+	#[inline]
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+		crate::join_styled(self, JoinStyle::new(glue))
+	}
+
+	#[inline]
+	fn oxford_count(&self) -> usize { self.0.len() }
+
+	fn oxford_walk<'b>(&'b self, cb: &mut dyn FnMut(&'b str)) {
+		for v in self.0.clone() { cb(v); }
+	}
+}
+
+/// # Join Reversed.
+///
+/// Join the items from the (already reversed) iterator.
+fn join_rev<'a, I>(mut iter: I, glue: Conjunction) -> Cow<'a, str>
+where I: ExactSizeIterator<Item=&'a str> + Clone {
+	match iter.len() {
+		0 | 1 => Cow::Borrowed(iter.next().unwrap_or_default()),
+		// The output is always owned here; the wrapper just needs to outlive
+		// the join.
+		_ => Cow::Owned(Rev(iter, PhantomData).oxford_join(glue).into_owned()),
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn t_oxford_join_rev() {
+		// Reversing the reversed set should put it back in order.
+		crate::tests::cross_check(|set, c| {
+			let rev: Vec<&str> = set.iter().rev().copied().collect();
+			let out = rev.oxford_join_rev(c).into_owned();
+			assert_eq!(rev.iter().copied().collect::<BTreeSet<_>>().oxford_join_rev(c), set.oxford_join_rev(c));
+			assert_eq!(
+				rev.iter().copied().enumerate().collect::<BTreeMap<_, _>>().oxford_join_rev(c),
+				out,
+			);
+			out
+		});
+
+		// Single items should be borrowed.
+		assert!(matches!(crate::tests::FRUIT[..1].oxford_join_rev(Conjunction::And), Cow::Borrowed("Apples")));
+	}
+}
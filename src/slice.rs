@@ -0,0 +1,157 @@
+/*!
+# Oxford Join: Heapless Joins.
+*/
+
+use crate::Conjunction;
+use core::fmt;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Capacity Error.
+///
+/// This is returned by [`oxford_join_slice`] when the output buffer is too
+/// small to hold the joined result.
+pub struct CapacityError {
+	/// # Required Length.
+	needed: usize,
+
+	/// # Available Length.
+	available: usize,
+}
+
+impl fmt::Display for CapacityError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"the joined output needs {} bytes, but the buffer only holds {}",
+			self.needed,
+			self.available,
+		)
+	}
+}
+
+impl core::error::Error for CapacityError {}
+
+impl CapacityError {
+	#[must_use]
+	/// # Needed.
+	///
+	/// Return the number of bytes required to hold the joined output.
+	pub const fn needed(&self) -> usize { self.needed }
+
+	#[must_use]
+	/// # Available.
+	///
+	/// Return the length of the buffer that was provided.
+	pub const fn available(&self) -> usize { self.available }
+}
+
+
+
+/// # Oxford Join (Slice).
+///
+/// Join the items into a caller-provided byte buffer, returning the number
+/// of bytes written. (The output is valid UTF-8, i.e.
+/// `str::from_utf8(&buf[..len])` will always succeed.)
+///
+/// Unlike [`OxfordJoin`](crate::OxfordJoin), this requires neither `alloc`
+/// nor a heap, making it suitable for strictly `no_std` environments.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, oxford_join_slice};
+///
+/// let mut buf = [0_u8; 64];
+/// let len = oxford_join_slice(&["Apples", "Oranges", "Bananas"], Conjunction::And, &mut buf)
+///     .unwrap();
+/// assert_eq!(&buf[..len], b"Apples, Oranges, and Bananas");
+///
+/// // Too small!
+/// let mut buf = [0_u8; 16];
+/// let err = oxford_join_slice(&["Apples", "Oranges"], Conjunction::And, &mut buf)
+///     .unwrap_err();
+/// assert_eq!(err.needed(), 18);
+/// ```
+///
+/// ## Errors
+///
+/// If the buffer is too small to hold the output, a [`CapacityError`] is
+/// returned instead, and the buffer is left untouched.
+pub fn oxford_join_slice<T: AsRef<str>>(
+	items: &[T],
+	glue: Conjunction,
+	buf: &mut [u8],
+) -> Result<usize, CapacityError> {
+	// Resolve the glue and figure out how much space we need.
+	let glue = items.last().map_or(glue, |last| glue.for_last(last.as_ref()));
+	let needed = items.iter()
+		.map(|s| s.as_ref().len())
+		.fold(glue.glue_len(items.len()), usize::saturating_add);
+	if buf.len() < needed {
+		return Err(CapacityError { needed, available: buf.len() });
+	}
+
+	let mut len = 0;
+	let mut push = |s: &str| {
+		let end = len + s.len();
+		buf[len..end].copy_from_slice(s.as_bytes());
+		len = end;
+	};
+
+	if let [first, mid @ .., last] = items {
+		push(first.as_ref());
+		for s in mid {
+			push(", ");
+			push(s.as_ref());
+		}
+		let parts = if mid.is_empty() { glue.pair_parts() } else { glue.list_parts(", ") };
+		for p in parts { push(p); }
+		push(last.as_ref());
+	}
+	else if let [only] = items { push(only.as_ref()); }
+
+	Ok(len)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use brunch as _;
+
+	#[test]
+	fn t_join_slice() {
+		const SET: [&str; 5] = ["Apples", "Bananas", "Carrots", "Dates", "Eggplant"];
+		const EXPECTED: [&str; 6] = [
+			"",
+			"Apples",
+			"Apples and Bananas",
+			"Apples, Bananas, and Carrots",
+			"Apples, Bananas, Carrots, and Dates",
+			"Apples, Bananas, Carrots, Dates, and Eggplant",
+		];
+
+		let mut buf = [0_u8; 64];
+		for (len, expected) in EXPECTED.into_iter().enumerate() {
+			let set = &SET[..len];
+			let written = oxford_join_slice(set, Conjunction::And, &mut buf).unwrap();
+			assert_eq!(core::str::from_utf8(&buf[..written]), Ok(expected));
+
+			// An exact fit is fine.
+			let mut exact = [0_u8; 64];
+			assert_eq!(oxford_join_slice(set, Conjunction::And, &mut exact[..written]), Ok(written));
+
+			// One less is not.
+			if written != 0 {
+				let mut small = [0_u8; 64];
+				let err = oxford_join_slice(set, Conjunction::And, &mut small[..written - 1]).unwrap_err();
+				assert_eq!(err.needed(), written);
+				assert_eq!(err.available(), written - 1);
+				assert!(small.iter().all(|&b| b == 0), "Buffer was modified.");
+			}
+		}
+	}
+}
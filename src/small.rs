@@ -0,0 +1,249 @@
+/*!
+# Oxford Join: Small Joins.
+*/
+
+use alloc::string::String;
+use core::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+use crate::{
+	Conjunction,
+	OxfordJoin,
+};
+
+
+
+/// # Inline Capacity.
+const INLINE: usize = 24;
+
+
+
+#[derive(Clone)]
+/// # Small Join.
+///
+/// This is the return type of [`OxfordJoin::oxford_join_small`], holding
+/// short joins — up to [`SmallJoin::INLINE_CAPACITY`] bytes — inline, on the
+/// stack, and only falling back to a heap-allocated `String` for longer
+/// output.
+///
+/// It dereferences to `str`, so can be used more or less like one.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordJoin};
+///
+/// let out = ["Apples", "Oranges"].oxford_join_small(Conjunction::And);
+/// assert_eq!(out, "Apples and Oranges");
+/// assert!(out.is_inline()); // No allocation!
+///
+/// let out = ["Apples", "Oranges", "Bananas"].oxford_join_small(Conjunction::And);
+/// assert_eq!(out, "Apples, Oranges, and Bananas");
+/// assert!(! out.is_inline()); // Too big.
+/// ```
+pub struct SmallJoin(Repr);
+
+#[derive(Clone)]
+/// # Storage.
+enum Repr {
+	/// # Stack.
+	Inline(Inline),
+
+	/// # Heap.
+	Heap(String),
+}
+
+impl AsRef<str> for SmallJoin {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl fmt::Debug for SmallJoin {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(self.as_str(), f)
+	}
+}
+
+impl Deref for SmallJoin {
+	type Target = str;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_str() }
+}
+
+impl fmt::Display for SmallJoin {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		<str as fmt::Display>::fmt(self.as_str(), f)
+	}
+}
+
+impl Eq for SmallJoin {}
+
+impl From<SmallJoin> for String {
+	#[inline]
+	fn from(src: SmallJoin) -> Self { src.into_string() }
+}
+
+impl hash::Hash for SmallJoin {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { self.as_str().hash(state); }
+}
+
+impl PartialEq for SmallJoin {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_str() == other.as_str() }
+}
+
+impl PartialEq<str> for SmallJoin {
+	#[inline]
+	fn eq(&self, other: &str) -> bool { self.as_str() == other }
+}
+
+impl PartialEq<&str> for SmallJoin {
+	#[inline]
+	fn eq(&self, other: &&str) -> bool { self.as_str() == *other }
+}
+
+impl SmallJoin {
+	/// # Inline Capacity.
+	///
+	/// Joins up to this many bytes are stored inline; anything longer is
+	/// moved to the heap.
+	pub const INLINE_CAPACITY: usize = INLINE;
+
+	/// # New.
+	///
+	/// Join the set, inline if possible.
+	pub(crate) fn new<S: OxfordJoin + ?Sized>(set: &S, glue: Conjunction) -> Self {
+		use fmt::Write;
+
+		let mut buf = Inline { buf: [0; INLINE], len: 0 };
+
+		// Pairs are common enough to warrant a shortcut.
+		if set.oxford_count() == 2 {
+			let mut pair = ["", ""];
+			let mut idx = 0;
+			set.oxford_walk(&mut |s| {
+				if let Some(p) = pair.get_mut(idx) { *p = s; }
+				idx += 1;
+			});
+			let [first, last] = pair;
+			let glue = glue.for_last(last);
+			let [pre, conj, post] = glue.pair_parts();
+			let parts = [first, pre, conj, post, last];
+			if parts.iter().all(|p| buf.write_str(p).is_ok()) {
+				Self(Repr::Inline(buf))
+			}
+			else { Self(Repr::Heap(parts.concat())) }
+		}
+		else if set.oxford_join_progress(glue, &mut buf, &mut |_| {}).is_ok() {
+			Self(Repr::Inline(buf))
+		}
+		else { Self(Repr::Heap(set.oxford_join(glue).into_owned())) }
+	}
+
+	#[must_use]
+	/// # As Str.
+	///
+	/// Return the joined value as a string slice.
+	pub fn as_str(&self) -> &str {
+		match &self.0 {
+			Repr::Inline(s) => s.as_str(),
+			Repr::Heap(s) => s.as_str(),
+		}
+	}
+
+	#[must_use]
+	/// # Into String.
+	///
+	/// Convert the value into an owned `String`, allocating if it was
+	/// inline.
+	pub fn into_string(self) -> String {
+		match self.0 {
+			Repr::Inline(s) => String::from(s.as_str()),
+			Repr::Heap(s) => s,
+		}
+	}
+
+	#[must_use]
+	/// # Is Inline?
+	///
+	/// Returns `true` if the value is stored on the stack.
+	pub const fn is_inline(&self) -> bool { matches!(self.0, Repr::Inline(_)) }
+}
+
+
+
+#[derive(Clone)]
+/// # Inline Buffer.
+///
+/// A fixed-size [`fmt::Write`] target that refuses to overflow.
+struct Inline {
+	/// # Buffer.
+	buf: [u8; INLINE],
+
+	/// # Length.
+	len: usize,
+}
+
+impl fmt::Write for Inline {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let end = self.len + s.len();
+		let dst = self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+		dst.copy_from_slice(s.as_bytes());
+		self.len = end;
+		Ok(())
+	}
+}
+
+impl Inline {
+	/// # As Str.
+	fn as_str(&self) -> &str {
+		// Only whole strs are ever written.
+		core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn t_oxford_join_small() {
+		crate::tests::cross_check(|set, c| {
+			let small = set.oxford_join_small(c);
+			assert_eq!(small.is_inline(), small.len() <= SmallJoin::INLINE_CAPACITY);
+			small.into_string()
+		});
+
+		// Right at the edge.
+		let set = ["abcdefghij", "klmnopq"]; // 10 + 5 + 7 = 22.
+		assert!(set.oxford_join_small(Conjunction::And).is_inline());
+		let set = ["abcdefghij", "klmnopqrs"]; // 24.
+		assert!(set.oxford_join_small(Conjunction::And).is_inline());
+		let set = ["abcdefghij", "klmnopqrst"]; // 25.
+		assert!(! set.oxford_join_small(Conjunction::And).is_inline());
+
+		// Pairs take a shortcut; make sure it resolves dynamic conjunctions
+		// and spills correctly.
+		let glue = Conjunction::Dynamic(|s| if s.starts_with('I') { "e" } else { "y" });
+		assert_eq!(["Juan", "Ignacio"].oxford_join_small(glue), "Juan e Ignacio");
+		assert_eq!(["Juan", "Pedro"].oxford_join_small(glue), "Juan y Pedro");
+		let small = ["abcdefghij", "klmnopqrst"].oxford_join_small(Conjunction::AndOr);
+		assert_eq!(small, "abcdefghij and/or klmnopqrst");
+		assert_eq!(small.into_string().capacity(), 28);
+
+		// Multi-byte characters shouldn't be split.
+		let set: Vec<&str> = ["😀"; 6].to_vec();
+		let small = set.oxford_join_small(Conjunction::Or);
+		assert!(! small.is_inline());
+		assert_eq!(small, set.oxford_join(Conjunction::Or).as_ref());
+	}
+}
@@ -0,0 +1,117 @@
+/*!
+# Oxford Join: Optional Joins.
+*/
+
+use alloc::borrow::Cow;
+use crate::{
+	Conjunction,
+	JoinStyle,
+	OxfordJoin,
+};
+
+
+
+/// # Oxford Join Some.
+///
+/// This extension trait joins slices and arrays of [`Option`]s, skipping
+/// over the `None`s.
+///
+/// The pair/list decision — and the output length — are based on the
+/// present (`Some`) values only, so the result is allocated exactly once.
+///
+/// Note that `Some("")` is still a value; combine with
+/// [`OxfordJoin::oxford_join_nonempty`] if empty strings should be skipped too.
+///
+/// ## Examples
+///
+/// ```
+/// use oxford_join::{Conjunction, OxfordJoinSome};
+///
+/// let set = [Some("Apples"), None, Some("Bananas")];
+/// assert_eq!(set.oxford_join_some(Conjunction::And), "Apples and Bananas");
+///
+/// let set = [None, Some(String::from("Apples")), None];
+/// assert_eq!(set.oxford_join_some(Conjunction::And), "Apples");
+///
+/// let set: [Option<&str>; 2] = [None, None];
+/// assert_eq!(set.oxford_join_some(Conjunction::And), "");
+/// ```
+pub trait OxfordJoinSome {
+	/// # Oxford Join (Some).
+	///
+	/// Join the `Some` values with Oxford Commas as necessary.
+	fn oxford_join_some(&self, glue: Conjunction) -> Cow<'_, str>;
+}
+
+impl<T: AsRef<str>> OxfordJoinSome for [Option<T>] {
+	fn oxford_join_some(&self, glue: Conjunction) -> Cow<'_, str> {
+		let set = Present(self);
+		let mut iter = self.iter().flatten();
+		match set.oxford_count() {
+			0 => Cow::Borrowed(""),
+			1 => Cow::Borrowed(iter.next().map_or("", T::as_ref)),
+			// The output is always owned here; the wrapper just needs to
+			// outlive the join.
+			_ => Cow::Owned(set.oxford_join(glue).into_owned()),
+		}
+	}
+}
+
+
+
+/// # Option Wrapper.
+///
+/// This wraps a slice of options so the `Some` values can be fed to the
+/// usual join machinery.
+struct Present<'a, T>(&'a [Option<T>]);
+
+impl<T: AsRef<str>> OxfordJoin for Present<'_, T> {
+	#[inline]
+	fn oxford_join(&self, glue: Conjunction) -> Cow<'_, str> {
+		crate::join_styled(self, JoinStyle::new(glue))
+	}
+
+	#[inline]
+	fn oxford_count(&self) -> usize { self.0.iter().flatten().count() }
+
+	fn oxford_walk<'a>(&'a self, cb: &mut dyn FnMut(&'a str)) {
+		for v in self.0.iter().flatten() { cb(v.as_ref()); }
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::{
+		string::String,
+		vec::Vec,
+	};
+
+	#[test]
+	fn t_oxford_join_some() {
+		crate::tests::cross_check(|set, c| {
+			// Interleave some Nones.
+			let set: Vec<Option<&str>> = set.iter()
+				.flat_map(|&s| [None, Some(s)])
+				.chain(core::iter::once(None))
+				.collect();
+			let out = set.oxford_join_some(c).into_owned();
+			assert_eq!(out.len(), out.capacity());
+			out
+		});
+
+		// Small sets should be borrowed.
+		assert!(matches!(
+			[None, Some("Apples")].oxford_join_some(Conjunction::And),
+			Cow::Borrowed("Apples"),
+		));
+
+		// Empty strings still count.
+		assert_eq!(
+			[Some(String::new()), None, Some(String::from("Apples"))].oxford_join_some(Conjunction::And),
+			" and Apples",
+		);
+	}
+}
@@ -0,0 +1,367 @@
+/*!
+# Oxford Join: Join Style.
+*/
+
+use crate::Conjunction;
+
+
+
+#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq)]
+/// # Join Style.
+///
+/// A [`Conjunction`] only controls the word (or symbol) binding the last
+/// entry of an Oxford-joined set. This struct pairs it with the separator
+/// used between _everything else_, for situations where the usual comma won't
+/// cut it.
+///
+/// Legal and academic writing, for example, often uses semicolons when the
+/// items themselves contain commas; see [`JoinStyle::SEMICOLON_AND`].
+///
+/// The separator only comes into play for sets with three or more items;
+/// pairs are always bound by the conjunction alone:
+///
+/// ```text
+/// first <CONJUNCTION> last
+/// first<SEPARATOR>second<SEPARATOR>…<SEPARATOR><CONJUNCTION> last
+/// ```
+///
+/// ## Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use oxford_join::{Conjunction, JoinStyle, OxfordJoin};
+///
+/// let set = ["Paris, France", "Rome, Italy", "Oslo, Norway"];
+/// assert_eq!(
+///     set.oxford_join_styled(JoinStyle::SEMICOLON_AND),
+///     "Paris, France; Rome, Italy; and Oslo, Norway",
+/// );
+///
+/// // The default style is equivalent to a plain conjunction.
+/// assert_eq!(
+///     set.oxford_join_styled(JoinStyle::new(Conjunction::Or)),
+///     set.oxford_join(Conjunction::Or),
+/// );
+/// # }
+/// ```
+pub struct JoinStyle<'a> {
+	/// # The Conjunction.
+	glue: Conjunction<'a>,
+
+	/// # The Separator.
+	sep: &'a str,
+
+	/// # The Pair Separator.
+	pair_sep: Option<&'a str>,
+
+	/// # The Last Separator.
+	last_sep: Option<&'a str>,
+
+	/// # Correlative Pairs.
+	correlative: bool,
+
+	/// # Tight Symbols.
+	tight: bool,
+
+	/// # "Or Both" Pairs.
+	or_both: bool,
+}
+
+impl Default for JoinStyle<'_> {
+	#[inline]
+	fn default() -> Self { Self::new(Conjunction::And) }
+}
+
+impl<'a> From<Conjunction<'a>> for JoinStyle<'a> {
+	#[inline]
+	fn from(src: Conjunction<'a>) -> Self { Self::new(src) }
+}
+
+impl JoinStyle<'_> {
+	/// # Semicolon And.
+	///
+	/// Separate entries with semicolons, and the last with `"; and"`:
+	///
+	/// ```text
+	/// first and second
+	/// first; second; and third
+	/// ```
+	///
+	/// Note that pairs are joined as usual, i.e. `"first and second"`; with
+	/// only two items there's nothing for the semicolon to disambiguate.
+	pub const SEMICOLON_AND: Self = Self::new(Conjunction::And).with_separator("; ");
+}
+
+impl<'a> JoinStyle<'a> {
+	#[must_use]
+	/// # New.
+	///
+	/// Return a new style for the given conjunction, using the standard
+	/// comma-space separator. Chain [`JoinStyle::with_separator`] to change it.
+	pub const fn new(glue: Conjunction<'a>) -> Self {
+		Self {
+			glue,
+			sep: ", ",
+			pair_sep: None,
+			last_sep: None,
+			correlative: false,
+			tight: false,
+			or_both: false,
+		}
+	}
+
+	#[must_use]
+	/// # With Separator.
+	///
+	/// Replace the separator used between entries of sets with three or more
+	/// items. The value is used as-is, so should include any trailing
+	/// whitespace, e.g. `"; "`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # #[cfg(feature = "alloc")] {
+	/// use oxford_join::{Conjunction, JoinStyle, OxfordJoin};
+	///
+	/// let style = JoinStyle::new(Conjunction::Or).with_separator("; ");
+	/// assert_eq!(
+	///     ["a", "b", "c"].oxford_join_styled(style),
+	///     "a; b; or c",
+	/// );
+	/// # }
+	/// ```
+	pub const fn with_separator(self, sep: &'a str) -> Self {
+		Self { sep, ..self }
+	}
+
+	#[must_use]
+	/// # With Pair Separator.
+	///
+	/// Bind two-item sets with this value _instead of_ the conjunction, for
+	/// mixed typographic styles like `"first — last"`. The value is used
+	/// as-is, so should include any surrounding whitespace.
+	///
+	/// This takes precedence over the other pair-specific options, i.e.
+	/// [`JoinStyle::with_correlative`] and [`JoinStyle::with_or_both`].
+	///
+	/// Sets of other sizes are unaffected.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # #[cfg(feature = "alloc")] {
+	/// use oxford_join::{Conjunction, JoinStyle, OxfordJoin};
+	///
+	/// let style = JoinStyle::new(Conjunction::And).with_pair_separator(" — ");
+	/// assert_eq!(
+	///     ["Apples", "Bananas"].oxford_join_styled(style),
+	///     "Apples — Bananas",
+	/// );
+	/// assert_eq!(
+	///     ["Apples", "Bananas", "Carrots"].oxford_join_styled(style),
+	///     "Apples, Bananas, and Carrots",
+	/// );
+	/// # }
+	/// ```
+	pub const fn with_pair_separator(self, sep: &'a str) -> Self {
+		Self { pair_sep: Some(sep), ..self }
+	}
+
+	#[must_use]
+	/// # With Last Separator.
+	///
+	/// Replace the punctuation preceding the conjunction in sets with three
+	/// or more items, independently of the separator used between the other
+	/// entries, for house styles like `"first, second — and third"`. The
+	/// value is used as-is, so should include any surrounding whitespace.
+	///
+	/// If unset, the regular [separator](JoinStyle::with_separator) is used.
+	///
+	/// Pairs, and [`Conjunction::OtherRaw`] conjunctions (which provide their
+	/// own punctuation), are unaffected.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # #[cfg(feature = "alloc")] {
+	/// use oxford_join::{Conjunction, JoinStyle, OxfordJoin};
+	///
+	/// let style = JoinStyle::new(Conjunction::And).with_last_separator(" — ");
+	/// assert_eq!(
+	///     ["Apples", "Bananas", "Carrots"].oxford_join_styled(style),
+	///     "Apples, Bananas — and Carrots",
+	/// );
+	///
+	/// // Drop the Oxford comma entirely!
+	/// let style = JoinStyle::new(Conjunction::And).with_last_separator(" ");
+	/// assert_eq!(
+	///     ["Apples", "Bananas", "Carrots"].oxford_join_styled(style),
+	///     "Apples, Bananas and Carrots",
+	/// );
+	/// # }
+	/// ```
+	pub const fn with_last_separator(self, sep: &'a str) -> Self {
+		Self { last_sep: Some(sep), ..self }
+	}
+
+	#[must_use]
+	/// # With Correlative Pairs.
+	///
+	/// Prefix two-item sets with the conjunction's
+	/// [correlative](Conjunction::correlative), if any, e.g.
+	/// `"neither first nor last"` instead of `"first nor last"`.
+	///
+	/// Sets of other sizes, and conjunctions without a correlative, are
+	/// unaffected.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # #[cfg(feature = "alloc")] {
+	/// use oxford_join::{Conjunction, JoinStyle, OxfordJoin};
+	///
+	/// let style = JoinStyle::new(Conjunction::Nor).with_correlative();
+	/// assert_eq!(
+	///     ["Apples", "Oranges"].oxford_join_styled(style),
+	///     "neither Apples nor Oranges",
+	/// );
+	///
+	/// // Three's a crowd.
+	/// assert_eq!(
+	///     ["Apples", "Oranges", "Bananas"].oxford_join_styled(style),
+	///     "Apples, Oranges, nor Bananas",
+	/// );
+	///
+	/// // "And" pairs get a "both".
+	/// let style = JoinStyle::new(Conjunction::And).with_correlative();
+	/// assert_eq!(
+	///     ["Apples", "Bananas"].oxford_join_styled(style),
+	///     "both Apples and Bananas",
+	/// );
+	/// # }
+	/// ```
+	pub const fn with_correlative(self) -> Self {
+		Self { correlative: true, ..self }
+	}
+
+	#[must_use]
+	/// # With Tight Symbols.
+	///
+	/// Drop the whitespace around [symbolic](Conjunction::is_symbol)
+	/// conjunctions — `&` and `+` — for compact output like `"A&B"`.
+	///
+	/// Oxford structure doesn't make much sense without spaces, so for sets
+	/// with three or more items, the symbol is inserted between _every_ item
+	/// instead, and the separator is ignored: `"A&B&C"`.
+	///
+	/// Word conjunctions are unaffected.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # #[cfg(feature = "alloc")] {
+	/// use oxford_join::{Conjunction, JoinStyle, OxfordJoin};
+	///
+	/// let style = JoinStyle::new(Conjunction::Ampersand).with_tight();
+	/// assert_eq!(["R", "D"].oxford_join_styled(style), "R&D");
+	/// assert_eq!(["A", "B", "C"].oxford_join_styled(style), "A&B&C");
+	///
+	/// // Words are still spaced.
+	/// let style = JoinStyle::new(Conjunction::And).with_tight();
+	/// assert_eq!(["A", "B", "C"].oxford_join_styled(style), "A, B, and C");
+	/// # }
+	/// ```
+	pub const fn with_tight(self) -> Self {
+		Self { tight: true, ..self }
+	}
+
+	#[must_use]
+	/// # With "Or Both" Pairs.
+	///
+	/// Expand two-item [`Conjunction::AndOr`] sets into the more formal
+	/// `"first, last, or both"` phrasing favored in legal writing, instead of
+	/// `"first and/or last"`. The separator is used between each part.
+	///
+	/// Sets of other sizes, and other conjunctions, are unaffected.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # #[cfg(feature = "alloc")] {
+	/// use oxford_join::{Conjunction, JoinStyle, OxfordJoin};
+	///
+	/// let style = JoinStyle::new(Conjunction::AndOr).with_or_both();
+	/// assert_eq!(
+	///     ["Apples", "Oranges"].oxford_join_styled(style),
+	///     "Apples, Oranges, or both",
+	/// );
+	///
+	/// // Three's a crowd.
+	/// assert_eq!(
+	///     ["Apples", "Oranges", "Bananas"].oxford_join_styled(style),
+	///     "Apples, Oranges, and/or Bananas",
+	/// );
+	/// # }
+	/// ```
+	pub const fn with_or_both(self) -> Self {
+		Self { or_both: true, ..self }
+	}
+
+	#[must_use]
+	/// # Conjunction.
+	///
+	/// Return the conjunction.
+	pub const fn conjunction(&self) -> Conjunction<'a> { self.glue }
+
+	#[must_use]
+	/// # Separator.
+	///
+	/// Return the separator.
+	pub const fn separator(&self) -> &'a str { self.sep }
+
+	#[must_use]
+	/// # Pair Separator.
+	///
+	/// Return the two-item separator, if any.
+	pub const fn pair_separator(&self) -> Option<&'a str> { self.pair_sep }
+
+	#[must_use]
+	/// # Last Separator.
+	///
+	/// Return the separator preceding the conjunction in sets with three or
+	/// more items. Unless [overridden](JoinStyle::with_last_separator), this
+	/// is the same as the regular separator.
+	pub const fn last_separator(&self) -> &'a str {
+		if let Some(sep) = self.last_sep { sep }
+		else { self.sep }
+	}
+
+	#[must_use]
+	/// # Is Tight?
+	///
+	/// Returns `true` if [symbolic](Conjunction::is_symbol) conjunctions
+	/// should be written without surrounding whitespace.
+	pub const fn is_tight(&self) -> bool { self.tight }
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	/// # Pair Prefix.
+	///
+	/// Return the correlative prefix to use for two-item sets, if any.
+	pub(crate) const fn pair_prefix(&self) -> Option<&'static str> {
+		if self.correlative && self.pair_sep.is_none() { self.glue.correlative() }
+		else { None }
+	}
+
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	/// # Pair Suffix.
+	///
+	/// Return the trailing phrase to use for two-item sets, if any.
+	pub(crate) const fn pair_suffix(&self) -> Option<&'static str> {
+		if self.or_both && self.pair_sep.is_none() && matches!(self.glue, Conjunction::AndOr) {
+			Some("or both")
+		}
+		else { None }
+	}
+}
@@ -0,0 +1,100 @@
+/*!
+# Oxford Join: Truncation Style.
+*/
+
+
+
+#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq)]
+/// # Truncation Style.
+///
+/// This holds the overflow phrasing used by the truncating join methods, so
+/// that it can be localized without a separate method for every language:
+///
+/// * The `ellipsis` is appended to joins cut short by
+///   [`OxfordJoin::oxford_join_clamped_with`](crate::OxfordJoin::oxford_join_clamped_with);
+/// * The `more` word follows the count of omitted items in
+///   [`OxfordJoin::oxford_join_truncated`](crate::OxfordJoin::oxford_join_truncated).
+///
+/// The [default](TruncStyle::DEFAULT) is English: `"…"` and `"more"`.
+///
+/// ## Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use oxford_join::{Conjunction, OxfordJoin, TruncStyle};
+///
+/// let set = ["Pommes", "Poires", "Prunes", "Pêches", "Figues"];
+///
+/// // English.
+/// assert_eq!(
+///     set.oxford_join_truncated(Conjunction::And, 2, TruncStyle::DEFAULT),
+///     "Pommes, Poires, and 3 more",
+/// );
+///
+/// // French.
+/// let style = TruncStyle::DEFAULT.with_more("autres");
+/// assert_eq!(
+///     set.oxford_join_truncated(Conjunction::Other("et"), 2, style),
+///     "Pommes, Poires, et 3 autres",
+/// );
+///
+/// // Plain ASCII.
+/// let style = TruncStyle::DEFAULT.with_ellipsis("...");
+/// assert_eq!(
+///     set.oxford_join_clamped_with(Conjunction::Other("et"), 20, style),
+///     "Pommes, Poires...",
+/// );
+/// # }
+/// ```
+pub struct TruncStyle<'a> {
+	/// # Ellipsis.
+	ellipsis: &'a str,
+
+	/// # More Word.
+	more: &'a str,
+}
+
+impl Default for TruncStyle<'_> {
+	#[inline]
+	fn default() -> Self { Self::DEFAULT }
+}
+
+impl TruncStyle<'_> {
+	/// # Default (English).
+	pub const DEFAULT: Self = Self::new("…", "more");
+}
+
+impl<'a> TruncStyle<'a> {
+	#[must_use]
+	/// # New.
+	///
+	/// Return a new style with the given ellipsis and more word. Both are
+	/// used as-is.
+	pub const fn new(ellipsis: &'a str, more: &'a str) -> Self {
+		Self { ellipsis, more }
+	}
+
+	#[must_use]
+	/// # With Ellipsis.
+	///
+	/// Replace the ellipsis.
+	pub const fn with_ellipsis(self, ellipsis: &'a str) -> Self {
+		Self { ellipsis, ..self }
+	}
+
+	#[must_use]
+	/// # With More Word.
+	///
+	/// Replace the more word.
+	pub const fn with_more(self, more: &'a str) -> Self {
+		Self { more, ..self }
+	}
+
+	#[must_use]
+	/// # Ellipsis.
+	pub const fn ellipsis(&self) -> &'a str { self.ellipsis }
+
+	#[must_use]
+	/// # More Word.
+	pub const fn more(&self) -> &'a str { self.more }
+}